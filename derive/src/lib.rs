@@ -0,0 +1,419 @@
+//! Implementation details for the `uuid` crate.
+//!
+//! This crate is not meant to be used directly. It provides
+//! the `UuidNewtype` derive, which is re-exported by `uuid`
+//! when its `derive` feature is enabled:
+//!
+//! ```toml
+//! [dependencies.uuid]
+//! features = ["v7", "derive"]
+//! ```
+//!
+//! See the documentation on `uuid::UuidNewtype` for usage.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitInt, LitStr, Path};
+
+#[proc_macro_derive(UuidNewtype, attributes(uuid))]
+pub fn derive_uuid_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+// The string formats that can be selected with `#[uuid(format = "...")]`
+const FORMATS: &[&str] = &["hyphenated", "simple", "urn", "braced"];
+
+// The serde formats that can be selected with `#[uuid(serde = "...")]`
+const SERDE_FORMATS: &[&str] = &[
+    "hyphenated",
+    "simple",
+    "urn",
+    "braced",
+    "compact",
+    "mysql_swapped",
+];
+
+// The versions that have a corresponding `UuidV*` type
+const VERSIONS: &[u8] = &[1, 3, 4, 5, 6, 7, 8];
+
+struct Options {
+    krate: Path,
+    version: Option<u8>,
+    format: Option<String>,
+    serde: Option<Option<String>>,
+}
+
+fn parse_options(input: &DeriveInput) -> syn::Result<Options> {
+    let mut options = Options {
+        krate: syn::parse_quote!(::uuid),
+        version: None,
+        format: None,
+        serde: None,
+    };
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("uuid"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                let path: LitStr = meta.value()?.parse()?;
+                options.krate = path.parse()?;
+
+                Ok(())
+            } else if meta.path.is_ident("version") {
+                let version: LitInt = meta.value()?.parse()?;
+                let parsed = version.base10_parse::<u8>()?;
+
+                if !VERSIONS.contains(&parsed) {
+                    return Err(syn::Error::new(
+                        version.span(),
+                        "expected a UUID version of 1, 3, 4, 5, 6, 7, or 8",
+                    ));
+                }
+
+                options.version = Some(parsed);
+
+                Ok(())
+            } else if meta.path.is_ident("format") {
+                let format: LitStr = meta.value()?.parse()?;
+
+                if !FORMATS.contains(&&*format.value()) {
+                    return Err(syn::Error::new(
+                        format.span(),
+                        "expected a format of `hyphenated`, `simple`, `urn`, or `braced`",
+                    ));
+                }
+
+                options.format = Some(format.value());
+
+                Ok(())
+            } else if meta.path.is_ident("serde") {
+                if meta.input.peek(syn::Token![=]) {
+                    let format: LitStr = meta.value()?.parse()?;
+
+                    if !SERDE_FORMATS.contains(&&*format.value()) {
+                        return Err(syn::Error::new(
+                            format.span(),
+                            "expected a module from `uuid::serde`, like `simple` or `compact`",
+                        ));
+                    }
+
+                    options.serde = Some(Some(format.value()));
+                } else {
+                    options.serde = Some(None);
+                }
+
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `uuid` attribute"))
+            }
+        })?;
+    }
+
+    Ok(options)
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    match input.data {
+        Data::Struct(ref data) if matches!(data.fields, Fields::Unnamed(ref fields) if fields.unnamed.len() == 1) =>
+            {}
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`UuidNewtype` can only be derived for tuple structs with a single `Uuid` field",
+            ))
+        }
+    }
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`UuidNewtype` can't be derived for generic types; consider `uuid::TypedUuid` instead",
+        ));
+    }
+
+    let options = parse_options(&input)?;
+
+    let name = &input.ident;
+    let krate = &options.krate;
+
+    // Check the version of a `Uuid`, returning a `Result<Uuid, uuid::Error>`
+    let check = |uuid: TokenStream2| match options.version {
+        Some(version) => {
+            let versioned = format_ident!("UuidV{}", version);
+
+            quote!(<#krate::#versioned as ::core::convert::TryFrom<#krate::Uuid>>::try_from(#uuid).map(#krate::Uuid::from))
+        }
+        None => quote!(::core::result::Result::<#krate::Uuid, #krate::Error>::Ok(#uuid)),
+    };
+
+    let accessors = quote! {
+        impl #name {
+            /// Get a reference to the underlying UUID.
+            #[inline]
+            pub const fn as_uuid(&self) -> &#krate::Uuid {
+                &self.0
+            }
+
+            /// Consume this value, returning the underlying UUID.
+            #[inline]
+            pub const fn into_uuid(self) -> #krate::Uuid {
+                self.0
+            }
+        }
+    };
+
+    let display = match options.format {
+        Some(ref format) => {
+            let adapter = format_ident!("{}", format);
+
+            quote!(::core::fmt::Display::fmt(&self.0.#adapter(), f))
+        }
+        None => quote!(::core::fmt::Display::fmt(&self.0, f)),
+    };
+
+    let parse = match options.format {
+        Some(ref format) => {
+            let adapter = format_ident!("{}", capitalize(format));
+
+            quote!(<#krate::fmt::#adapter as ::core::str::FromStr>::from_str(s)?.into_uuid())
+        }
+        None => quote!(#krate::Uuid::parse_str(s)?),
+    };
+    let check_parsed = check(quote!(uuid));
+
+    let fmt = quote! {
+        impl ::core::fmt::Display for #name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #display
+            }
+        }
+
+        impl ::core::str::FromStr for #name {
+            type Err = #krate::Error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                let uuid = #parse;
+
+                #check_parsed.map(#name)
+            }
+        }
+    };
+
+    let check_converted = check(quote!(uuid));
+    let conversions = match options.version {
+        Some(version) => {
+            let versioned = format_ident!("UuidV{}", version);
+
+            quote! {
+                impl ::core::convert::TryFrom<#krate::Uuid> for #name {
+                    type Error = #krate::Error;
+
+                    fn try_from(uuid: #krate::Uuid) -> ::core::result::Result<Self, Self::Error> {
+                        #check_converted.map(#name)
+                    }
+                }
+
+                impl ::core::convert::From<#krate::#versioned> for #name {
+                    fn from(uuid: #krate::#versioned) -> Self {
+                        #name(#krate::Uuid::from(uuid))
+                    }
+                }
+
+                impl ::core::convert::TryFrom<#name> for #krate::#versioned {
+                    type Error = #krate::Error;
+
+                    fn try_from(value: #name) -> ::core::result::Result<Self, Self::Error> {
+                        <#krate::#versioned as ::core::convert::TryFrom<#krate::Uuid>>::try_from(value.0)
+                    }
+                }
+            }
+        }
+        None => quote! {
+            impl ::core::convert::From<#krate::Uuid> for #name {
+                fn from(uuid: #krate::Uuid) -> Self {
+                    #name(uuid)
+                }
+            }
+        },
+    };
+
+    let common = quote! {
+        impl ::core::convert::From<#name> for #krate::Uuid {
+            fn from(value: #name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::convert::AsRef<#krate::Uuid> for #name {
+            fn as_ref(&self) -> &#krate::Uuid {
+                &self.0
+            }
+        }
+    };
+
+    let constructors = options
+        .version
+        .map(|version| constructors(krate, name, version))
+        .unwrap_or_default();
+
+    let serde = match options.serde {
+        Some(ref serde) => {
+            let serde_format = serde.as_ref().or(options.format.as_ref());
+
+            let (serialize, deserialize) = match serde_format {
+                Some(format) => {
+                    let module = format_ident!("{}", format);
+
+                    (
+                        quote!(#krate::serde::#module::serialize(&self.0, serializer)),
+                        quote!(#krate::serde::#module::deserialize(deserializer)?),
+                    )
+                }
+                None => (
+                    quote!(#krate::__macro_support::serde::Serialize::serialize(&self.0, serializer)),
+                    quote!(<#krate::Uuid as #krate::__macro_support::serde::Deserialize<'de>>::deserialize(deserializer)?),
+                ),
+            };
+            let check_deserialized = check(quote!(uuid));
+
+            quote! {
+                impl #krate::__macro_support::serde::Serialize for #name {
+                    fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                    where
+                        S: #krate::__macro_support::serde::Serializer,
+                    {
+                        #serialize
+                    }
+                }
+
+                impl<'de> #krate::__macro_support::serde::Deserialize<'de> for #name {
+                    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                    where
+                        D: #krate::__macro_support::serde::Deserializer<'de>,
+                    {
+                        let uuid = #deserialize;
+
+                        #check_deserialized
+                            .map(#name)
+                            .map_err(<D::Error as #krate::__macro_support::serde::de::Error>::custom)
+                    }
+                }
+            }
+        }
+        None => TokenStream2::new(),
+    };
+
+    Ok(quote! {
+        #accessors
+        #fmt
+        #conversions
+        #common
+        #constructors
+        #serde
+    })
+}
+
+// Constructors are gated on the features of the `uuid` crate,
+// since they aren't visible to the crate invoking the derive
+fn constructors(krate: &Path, name: &syn::Ident, version: u8) -> TokenStream2 {
+    match version {
+        1 | 6 => {
+            let new = format_ident!("new_v{}", version);
+            let now = format_ident!("now_v{}", version);
+            let if_new = format_ident!("__uuid_if_v{}", version);
+            let if_now = format_ident!("__uuid_if_v{}_now", version);
+
+            quote! {
+                #krate::#if_new! {
+                    impl #name {
+                        /// Create a new UUID using the given timestamp and node ID.
+                        pub fn #new(ts: #krate::Timestamp, node_id: &[u8; 6]) -> Self {
+                            #name(#krate::Uuid::#new(ts, node_id))
+                        }
+                    }
+                }
+
+                #krate::#if_now! {
+                    impl #name {
+                        /// Create a new UUID using the current system time and the given node ID.
+                        pub fn #now(node_id: &[u8; 6]) -> Self {
+                            #name(#krate::Uuid::#now(node_id))
+                        }
+                    }
+                }
+            }
+        }
+        3 | 5 => {
+            let new = format_ident!("new_v{}", version);
+            let if_new = format_ident!("__uuid_if_v{}", version);
+
+            quote! {
+                #krate::#if_new! {
+                    impl #name {
+                        /// Create a new UUID using a hash of the given namespace and name.
+                        pub fn #new(namespace: &#krate::Uuid, name: &[u8]) -> Self {
+                            #name(#krate::Uuid::#new(namespace, name))
+                        }
+                    }
+                }
+            }
+        }
+        4 => quote! {
+            #krate::__uuid_if_v4! {
+                impl #name {
+                    /// Create a new random UUID.
+                    pub fn new_v4() -> Self {
+                        #name(#krate::Uuid::new_v4())
+                    }
+                }
+            }
+        },
+        7 => quote! {
+            #krate::__uuid_if_v7! {
+                impl #name {
+                    /// Create a new UUID using the given timestamp and random bytes.
+                    pub fn new_v7(ts: #krate::Timestamp) -> Self {
+                        #name(#krate::Uuid::new_v7(ts))
+                    }
+                }
+            }
+
+            #krate::__uuid_if_v7_now! {
+                impl #name {
+                    /// Create a new UUID using the current system time.
+                    pub fn now_v7() -> Self {
+                        #name(#krate::Uuid::now_v7())
+                    }
+                }
+            }
+        },
+        8 => quote! {
+            #krate::__uuid_if_v8! {
+                impl #name {
+                    /// Create a new UUID using the given custom bytes.
+                    pub const fn new_v8(buf: [u8; 16]) -> Self {
+                        #name(#krate::Uuid::new_v8(buf))
+                    }
+                }
+            }
+        },
+        _ => TokenStream2::new(),
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,417 @@
+//! Abbreviating UUIDs to their shortest unambiguous prefix.
+//!
+//! Like abbreviated git hashes, a UUID can be shown as just the first few hex digits
+//! of its simple form, as long as no other UUID in the same set starts with them.
+//! [`abbreviate`] and [`abbreviations`] find the shortest such prefix for UUIDs in a
+//! sorted slice, and [`resolve`] finds the UUIDs that an abbreviation could refer to.
+//!
+//! # Examples
+//!
+//! ```
+//! # use uuid::{Uuid, abbrev::{self, Resolution}};
+//! # fn main() -> Result<(), uuid::Error> {
+//! let mut uuids = [
+//!     Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?,
+//!     Uuid::parse_str("550e8473-24c8-4d3e-9ab1-a21f0b5f7a2d")?,
+//!     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+//! ];
+//! uuids.sort();
+//!
+//! let short = abbrev::abbreviations(&uuids, 4)
+//!     .map(|abbrev| abbrev.to_string())
+//!     .collect::<Vec<_>>();
+//!
+//! assert_eq!(["550e840", "550e847", "67e5"], short.as_slice());
+//!
+//! assert_eq!(Resolution::Unique(uuids[2]), abbrev::resolve(&uuids, "67e5")?);
+//! assert_eq!(Resolution::Ambiguous(&uuids[..2]), abbrev::resolve(&uuids, "550e")?);
+//! assert_eq!(Resolution::NotFound, abbrev::resolve(&uuids, "abc")?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::*,
+    parser::HEX_TABLE,
+    std::{fmt, iter::FusedIterator},
+    Uuid,
+};
+
+/// The minimum abbreviation length git uses for object hashes.
+pub const DEFAULT_MIN_LEN: usize = 7;
+
+/// An abbreviated [`Uuid`].
+///
+/// This formats as the first [`length`](Abbrev::length) lower-case hex digits
+/// of the UUID's simple form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Abbrev {
+    uuid: Uuid,
+    len: u8,
+}
+
+impl Abbrev {
+    /// The number of hex digits in the abbreviation, between 1 and 32.
+    pub const fn length(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Consumes the [`Abbrev`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.uuid
+    }
+}
+
+impl fmt::Display for Abbrev {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let simple = self.uuid.simple();
+        let mut buf = Uuid::encode_buffer();
+
+        f.write_str(&simple.encode_lower(&mut buf)[..self.length()])
+    }
+}
+
+/// Abbreviate a [`Uuid`] so it's distinct from every other UUID in `sorted`.
+///
+/// The abbreviation is at least `min_len` hex digits long, and only longer if
+/// that's needed to tell it apart from its neighbors in the set. The UUID doesn't
+/// need to be in the set itself. Duplicates of it in the set are ignored.
+///
+/// The slice must be sorted, like the input to [`slice::binary_search`]. If it
+/// isn't, the abbreviation may not be unique.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, abbrev};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuids = [
+///     Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?,
+///     Uuid::parse_str("550e8473-24c8-4d3e-9ab1-a21f0b5f7a2d")?,
+/// ];
+///
+/// assert_eq!("550e840", abbrev::abbreviate(&uuids, &uuids[0], 1).to_string());
+/// assert_eq!("550e8400e2", abbrev::abbreviate(&uuids, &uuids[0], 10).to_string());
+/// # Ok(())
+/// # }
+/// ```
+pub fn abbreviate(sorted: &[Uuid], uuid: &Uuid, min_len: usize) -> Abbrev {
+    let start = sorted.partition_point(|other| other < uuid);
+    let end = start + sorted[start..].partition_point(|other| other == uuid);
+
+    let shared = match (start.checked_sub(1), sorted.get(end)) {
+        (Some(prev), Some(next)) => {
+            shared_digits(&sorted[prev], uuid).max(shared_digits(uuid, next))
+        }
+        (Some(prev), None) => shared_digits(&sorted[prev], uuid),
+        (None, Some(next)) => shared_digits(uuid, next),
+        (None, None) => 0,
+    };
+
+    Abbrev {
+        uuid: *uuid,
+        // A distinct neighbor shares at most 31 digits, so this is at most 32
+        len: (shared + 1).max(min_len.min(32)) as u8,
+    }
+}
+
+/// Abbreviate every [`Uuid`] in `sorted`, in order.
+///
+/// Each abbreviation is the same as calling [`abbreviate`] for that UUID.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, abbrev};
+/// let uuids = [Uuid::from_u128(0x1), Uuid::from_u128(0x2)];
+///
+/// let lengths = abbrev::abbreviations(&uuids, abbrev::DEFAULT_MIN_LEN)
+///     .map(|abbrev| abbrev.length())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!([32, 32], lengths.as_slice());
+/// ```
+pub fn abbreviations(sorted: &[Uuid], min_len: usize) -> Abbreviations<'_> {
+    Abbreviations {
+        sorted,
+        index: 0,
+        min_len,
+    }
+}
+
+/// An iterator over the abbreviations of a sorted set of UUIDs.
+///
+/// This is returned by [`abbreviations`].
+#[derive(Clone, Debug)]
+pub struct Abbreviations<'a> {
+    sorted: &'a [Uuid],
+    index: usize,
+    min_len: usize,
+}
+
+impl Iterator for Abbreviations<'_> {
+    type Item = Abbrev;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let uuid = self.sorted.get(self.index)?;
+        self.index += 1;
+
+        Some(abbreviate(self.sorted, uuid, self.min_len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.sorted.len() - self.index;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Abbreviations<'_> {}
+
+impl FusedIterator for Abbreviations<'_> {}
+
+/// The UUIDs that an abbreviation could refer to.
+///
+/// This is returned by [`resolve`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Resolution<'a> {
+    /// No UUID starts with the abbreviation.
+    NotFound,
+    /// Exactly one UUID starts with the abbreviation.
+    Unique(Uuid),
+    /// More than one UUID starts with the abbreviation.
+    ///
+    /// The slice contains all of them, in order.
+    Ambiguous(&'a [Uuid]),
+}
+
+/// Find the UUIDs in `sorted` that start with an abbreviation.
+///
+/// The abbreviation is between 1 and 32 hex digits in either case. Hyphens are
+/// ignored, so a prefix of a hyphenated UUID can be used too.
+///
+/// The slice must be sorted, like the input to [`slice::binary_search`]. If it
+/// isn't, matching UUIDs may be missed.
+///
+/// # Errors
+///
+/// Returns an error if the abbreviation contains characters other than hex digits
+/// and hyphens, or if it doesn't contain between 1 and 32 hex digits.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, abbrev::{self, Resolution}};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuids = [Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?];
+///
+/// assert_eq!(Resolution::Unique(uuids[0]), abbrev::resolve(&uuids, "67E55044-10")?);
+///
+/// assert!(abbrev::resolve(&uuids, "67e5…").is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn resolve<'a>(sorted: &'a [Uuid], abbrev: &str) -> Result<Resolution<'a>, Error> {
+    let mut prefix = 0u128;
+    let mut digits = 0;
+
+    for (index, byte) in abbrev.bytes().enumerate() {
+        if byte == b'-' {
+            continue;
+        }
+
+        let digit = HEX_TABLE[byte as usize];
+        if digit == 0xff {
+            return Err(Error(ErrorKind::ParseChar {
+                // Every byte before the index is ASCII, so it's at a character boundary
+                character: abbrev[index..].chars().next().unwrap(),
+                index: index + 1,
+            }));
+        }
+
+        digits += 1;
+        if digits <= 32 {
+            prefix = (prefix << 4) | digit as u128;
+        }
+    }
+
+    if digits == 0 || digits > 32 {
+        return Err(Error(ErrorKind::AbbrevLength { len: digits }));
+    }
+
+    // The range of values that start with the prefix
+    let rest = (32 - digits) * 4;
+    let low = prefix.checked_shl(rest as u32).unwrap_or(0);
+    let high = low | u128::MAX.checked_shr(128 - rest as u32).unwrap_or(0);
+
+    let start = sorted.partition_point(|uuid| uuid.as_u128() < low);
+    let end = start + sorted[start..].partition_point(|uuid| uuid.as_u128() <= high);
+
+    Ok(match &sorted[start..end] {
+        [] => Resolution::NotFound,
+        [uuid] => Resolution::Unique(*uuid),
+        matches => Resolution::Ambiguous(matches),
+    })
+}
+
+// The number of leading hex digits that two UUIDs have in common
+fn shared_digits(a: &Uuid, b: &Uuid) -> usize {
+    ((a.as_u128() ^ b.as_u128()).leading_zeros() / 4) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    const SORTED: &[Uuid] = &[
+        Uuid::from_u128(0x00000000_0000_0000_0000_000000000001),
+        Uuid::from_u128(0x550e8400_e29b_41d4_a716_446655440000),
+        Uuid::from_u128(0x550e8400_e29b_41d4_a716_446655440001),
+        Uuid::from_u128(0x550e8473_24c8_4d3e_9ab1_a21f0b5f7a2d),
+        Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8),
+        Uuid::from_u128(0xffffffff_ffff_ffff_ffff_ffffffffffff),
+    ];
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_abbreviations() {
+        let expected = [
+            "0",
+            "550e8400e29b41d4a716446655440000",
+            "550e8400e29b41d4a716446655440001",
+            "550e847",
+            "6",
+            "f",
+        ];
+
+        assert_eq!(expected.len(), abbreviations(SORTED, 1).len());
+
+        for (abbrev, expected) in abbreviations(SORTED, 1).zip(expected) {
+            assert_eq!(expected, abbrev.to_string());
+            assert_eq!(expected.len(), abbrev.length());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_abbreviations_resolve() {
+        for abbrev in abbreviations(SORTED, 1) {
+            assert_eq!(
+                Resolution::Unique(abbrev.into_uuid()),
+                resolve(SORTED, &abbrev.to_string()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_abbreviate_min_len() {
+        assert_eq!("6", abbreviate(SORTED, &SORTED[4], 0).to_string());
+        assert_eq!(
+            "67e5504",
+            abbreviate(SORTED, &SORTED[4], DEFAULT_MIN_LEN).to_string()
+        );
+        assert_eq!("550e847", abbreviate(SORTED, &SORTED[3], 4).to_string());
+        assert_eq!(32, abbreviate(SORTED, &SORTED[4], 100).length());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_abbreviate_outside_set() {
+        let uuid = Uuid::from_u128(0x550e8500_0000_0000_0000_000000000000);
+
+        assert_eq!("550e85", abbreviate(SORTED, &uuid, 1).to_string());
+        assert_eq!("5", abbreviate(&[], &uuid, 1).to_string());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_abbreviate_duplicates() {
+        let sorted = [SORTED[4], SORTED[4], SORTED[5]];
+
+        assert_eq!("6", abbreviate(&sorted, &SORTED[4], 1).to_string());
+        assert_eq!(
+            Resolution::Ambiguous(&sorted[..2]),
+            resolve(&sorted, "6").unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_resolve() {
+        assert_eq!(
+            Resolution::Ambiguous(&SORTED[1..4]),
+            resolve(SORTED, "550E84").unwrap()
+        );
+        assert_eq!(
+            Resolution::Ambiguous(&SORTED[1..3]),
+            resolve(SORTED, "550e8400-e29b").unwrap()
+        );
+        assert_eq!(
+            Resolution::Unique(SORTED[3]),
+            resolve(SORTED, "550e8473-24c8-4d3e-9ab1-a21f0b5f7a2d").unwrap()
+        );
+        assert_eq!(Resolution::Unique(SORTED[0]), resolve(SORTED, "0").unwrap());
+        assert_eq!(
+            Resolution::Unique(SORTED[5]),
+            resolve(SORTED, "ff").unwrap()
+        );
+        assert_eq!(Resolution::NotFound, resolve(SORTED, "1").unwrap());
+        assert_eq!(Resolution::NotFound, resolve(&[], "1").unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_resolve_invalid() {
+        assert_eq!(
+            Error(ErrorKind::AbbrevLength { len: 0 }),
+            resolve(SORTED, "").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::AbbrevLength { len: 0 }),
+            resolve(SORTED, "-").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::AbbrevLength { len: 33 }),
+            resolve(SORTED, "550e8473-24c8-4d3e-9ab1-a21f0b5f7a2d0").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: '…',
+                index: 7
+            }),
+            resolve(SORTED, "550e84…").unwrap_err()
+        );
+    }
+}
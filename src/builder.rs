@@ -13,7 +13,9 @@
 //!
 //! [`Uuid`]: ../struct.Uuid.html
 
-use crate::{error::*, timestamp, Bytes, Uuid, Variant, Version};
+use core::ops::{Bound, RangeBounds, RangeInclusive};
+
+use crate::{error::*, timestamp, Bytes, Timestamp, Uuid, Variant, Version};
 
 /// A builder for creating a UUID.
 ///
@@ -400,6 +402,37 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID using the supplied bytes with the time-low and time-high fields swapped.
+    ///
+    /// This reverses the binary encoding produced by MySQL's `UUID_TO_BIN(uuid, 1)` function,
+    /// and is equivalent to MySQL's `BIN_TO_UUID(bytes, 1)` function. See
+    /// [`Uuid::to_mysql_swapped_bytes`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let bytes = [
+    ///     0x10, 0x26, 0xba, 0xba, 0x6c, 0xcd, 0x78, 0x0c, 0x95, 0x64,
+    ///     0x5b, 0x8c, 0x65, 0x60, 0x24, 0xdb,
+    /// ];
+    ///
+    /// let uuid = Uuid::from_mysql_swapped_bytes(bytes);
+    ///
+    /// assert_eq!(
+    ///     "6ccd780c-baba-1026-9564-5b8c656024db",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_mysql_swapped_bytes(b: Bytes) -> Uuid {
+        Uuid([
+            b[4], b[5], b[6], b[7], b[2], b[3], b[0], b[1], b[8], b[9], b[10], b[11], b[12], b[13],
+            b[14], b[15],
+        ])
+    }
+
     /// Creates a reference to a UUID from a reference to the supplied bytes.
     ///
     /// # Examples
@@ -438,6 +471,232 @@ impl Uuid {
     // NOTE: There is no `from_u128_ref` because in little-endian
     // environments the value isn't properly encoded. Callers would
     // need to use `.to_be()` themselves.
+
+    /// The lowest version 7 UUID that can be generated for the given timestamp.
+    ///
+    /// The returned UUID has all of its counter and random bits unset, so it sorts
+    /// before any other version 7 UUID generated within the same millisecond.
+    /// Any sub-millisecond precision or counter in the timestamp is ignored.
+    ///
+    /// This method doesn't require the `v7` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// assert_eq!(
+    ///     "015cb15a-86d8-7000-8000-000000000000",
+    ///     Uuid::v7_min_for(ts).hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn v7_min_for(ts: Timestamp) -> Self {
+        Self::v7_min_for_millis(v7_millis(&ts))
+    }
+
+    /// The highest version 7 UUID that can be generated for the given timestamp.
+    ///
+    /// The returned UUID has all of its counter and random bits set, so it sorts
+    /// after any other version 7 UUID generated within the same millisecond.
+    /// Any sub-millisecond precision or counter in the timestamp is ignored.
+    ///
+    /// This method doesn't require the `v7` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// assert_eq!(
+    ///     "015cb15a-86d8-7fff-bfff-ffffffffffff",
+    ///     Uuid::v7_max_for(ts).hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn v7_max_for(ts: Timestamp) -> Self {
+        Self::v7_max_for_millis(v7_millis(&ts))
+    }
+
+    /// Get the inclusive bounds of version 7 UUIDs generated within a range of timestamps.
+    ///
+    /// This is useful for querying a range of time-ordered keys, such as in a database index.
+    /// Timestamps are truncated to millisecond precision, so an excluded bound will exclude
+    /// its entire millisecond.
+    ///
+    /// If there are no version 7 UUIDs that fall within the range of timestamps then the
+    /// returned range will be empty.
+    ///
+    /// This method doesn't require the `v7` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let start = Timestamp::from_unix(NoContext, 1497624119, 0);
+    /// let end = Timestamp::from_unix(NoContext, 1497624120, 0);
+    ///
+    /// let range = Uuid::v7_range(start..end);
+    ///
+    /// assert_eq!(
+    ///     "015cb15a-86d8-7000-8000-000000000000",
+    ///     range.start().hyphenated().to_string(),
+    /// );
+    /// assert_eq!(
+    ///     "015cb15a-8abf-7fff-bfff-ffffffffffff",
+    ///     range.end().hyphenated().to_string(),
+    /// );
+    /// ```
+    pub fn v7_range(range: impl RangeBounds<Timestamp>) -> RangeInclusive<Self> {
+        let start = match range.start_bound() {
+            Bound::Included(ts) => Some(v7_millis(ts)),
+            Bound::Excluded(ts) => match v7_millis(ts) {
+                MAX_UNIX_TIMESTAMP_MILLIS => None,
+                millis => Some(millis + 1),
+            },
+            Bound::Unbounded => Some(0),
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(ts) => Some(v7_millis(ts)),
+            Bound::Excluded(ts) => v7_millis(ts).checked_sub(1),
+            Bound::Unbounded => Some(MAX_UNIX_TIMESTAMP_MILLIS),
+        };
+
+        match (start, end) {
+            (Some(start), Some(end)) => {
+                Self::v7_min_for_millis(start)..=Self::v7_max_for_millis(end)
+            }
+            _ => Uuid::max()..=Uuid::nil(),
+        }
+    }
+
+    /// The lowest version 6 UUID that can be generated for the given timestamp.
+    ///
+    /// The returned UUID has its clock sequence and node ID unset, so it sorts
+    /// before any other version 6 UUID generated within the same 100ns tick.
+    /// Any counter in the timestamp is ignored.
+    ///
+    /// This method doesn't require the `v6` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// assert_eq!(
+    ///     "1e752a1f-3b49-658c-8000-000000000000",
+    ///     Uuid::v6_min_for(ts).hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn v6_min_for(ts: Timestamp) -> Self {
+        Self::v6_min_for_ticks(ts.to_gregorian().0 & MAX_GREGORIAN_TICKS)
+    }
+
+    /// The highest version 6 UUID that can be generated for the given timestamp.
+    ///
+    /// The returned UUID has all of its clock sequence and node ID bits set, so it
+    /// sorts after any other version 6 UUID generated within the same 100ns tick.
+    /// Any counter in the timestamp is ignored.
+    ///
+    /// This method doesn't require the `v6` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// assert_eq!(
+    ///     "1e752a1f-3b49-658c-bfff-ffffffffffff",
+    ///     Uuid::v6_max_for(ts).hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn v6_max_for(ts: Timestamp) -> Self {
+        Self::v6_max_for_ticks(ts.to_gregorian().0 & MAX_GREGORIAN_TICKS)
+    }
+
+    /// Get the inclusive bounds of version 6 UUIDs generated within a range of timestamps.
+    ///
+    /// This is useful for querying a range of time-ordered keys, such as in a database index.
+    /// Timestamps are truncated to 100ns precision, so an excluded bound will exclude
+    /// its entire tick.
+    ///
+    /// If there are no version 6 UUIDs that fall within the range of timestamps then the
+    /// returned range will be empty.
+    ///
+    /// This method doesn't require the `v6` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let start = Timestamp::from_unix(NoContext, 1497624119, 0);
+    /// let end = Timestamp::from_unix(NoContext, 1497624120, 0);
+    ///
+    /// let range = Uuid::v6_range(start..end);
+    ///
+    /// assert_eq!(
+    ///     "1e752a1f-3b49-6580-8000-000000000000",
+    ///     range.start().hyphenated().to_string(),
+    /// );
+    /// assert_eq!(
+    ///     "1e752a1f-44d2-6bff-bfff-ffffffffffff",
+    ///     range.end().hyphenated().to_string(),
+    /// );
+    /// ```
+    pub fn v6_range(range: impl RangeBounds<Timestamp>) -> RangeInclusive<Self> {
+        let ticks = |ts: &Timestamp| ts.to_gregorian().0 & MAX_GREGORIAN_TICKS;
+
+        let start = match range.start_bound() {
+            Bound::Included(ts) => Some(ticks(ts)),
+            Bound::Excluded(ts) => match ticks(ts) {
+                MAX_GREGORIAN_TICKS => None,
+                ticks => Some(ticks + 1),
+            },
+            Bound::Unbounded => Some(0),
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(ts) => Some(ticks(ts)),
+            Bound::Excluded(ts) => ticks(ts).checked_sub(1),
+            Bound::Unbounded => Some(MAX_GREGORIAN_TICKS),
+        };
+
+        match (start, end) {
+            (Some(start), Some(end)) => Self::v6_min_for_ticks(start)..=Self::v6_max_for_ticks(end),
+            _ => Uuid::max()..=Uuid::nil(),
+        }
+    }
+
+    const fn v7_min_for_millis(millis: u64) -> Self {
+        timestamp::encode_unix_timestamp_millis(millis, &[0; 10])
+    }
+
+    const fn v7_max_for_millis(millis: u64) -> Self {
+        timestamp::encode_unix_timestamp_millis(millis, &[0xFF; 10])
+    }
+
+    const fn v6_min_for_ticks(ticks: u64) -> Self {
+        timestamp::encode_sorted_gregorian_timestamp(ticks, 0, &[0; 6])
+    }
+
+    const fn v6_max_for_ticks(ticks: u64) -> Self {
+        timestamp::encode_sorted_gregorian_timestamp(ticks, u16::MAX, &[0xFF; 6])
+    }
+}
+
+// The largest timestamp that can be encoded in a version 7 UUID (48 bits)
+const MAX_UNIX_TIMESTAMP_MILLIS: u64 = u64::MAX >> 16;
+
+// The largest timestamp that can be encoded in a version 6 UUID (60 bits)
+const MAX_GREGORIAN_TICKS: u64 = u64::MAX >> 4;
+
+// Version 7 UUIDs only store the least significant 48 bits of the timestamp;
+// this needs to match the truncation in `Uuid::new_v7`
+const fn v7_millis(ts: &Timestamp) -> u64 {
+    ts.unix_millis() & MAX_UNIX_TIMESTAMP_MILLIS
 }
 
 impl Builder {
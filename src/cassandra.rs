@@ -0,0 +1,240 @@
+//! Compatibility with Cassandra's `timeuuid` type.
+//!
+//! Cassandra and ScyllaDB store version 1 UUIDs in `timeuuid` columns. They don't
+//! order these values by their bytes. Instead, they compare the embedded timestamp
+//! first, and then the remaining clock sequence and node bytes as signed integers.
+//!
+//! # References
+//!
+//! * [Cassandra `timeuuid` functions](https://cassandra.apache.org/doc/latest/cassandra/developing/cql/functions.html#timeuuid-functions)
+
+use core::cmp::Ordering;
+
+use crate::{
+    timestamp::{self, UUID_TICKS_BETWEEN_EPOCHS},
+    Timestamp, Uuid,
+};
+
+// The clock sequence and node used by `minTimeuuid`.
+// Every byte is the smallest signed value (`-128`).
+const MIN_CLOCK_SEQ_AND_NODE: u64 = 0x8080_8080_8080_8080;
+
+// The clock sequence and node used by `maxTimeuuid`.
+// Every byte is the largest signed value (`127`).
+const MAX_CLOCK_SEQ_AND_NODE: u64 = 0x7f7f_7f7f_7f7f_7f7f;
+
+impl Uuid {
+    /// Compare two UUIDs the same way Cassandra and ScyllaDB compare `timeuuid` values.
+    ///
+    /// UUIDs are ordered by their version and embedded 60-bit timestamp first. Ties are broken
+    /// by comparing the clock sequence and node bytes as signed integers. This is different
+    /// from the ordering of [`Uuid`] itself, which compares raw bytes, and so will order
+    /// version 1 UUIDs by the least significant bits of their timestamp.
+    ///
+    /// This method is intended for version 1 UUIDs, but won't panic for other versions.
+    /// Those are ordered by their version first, then their bytes interpreted as though
+    /// they were a version 1 UUID.
+    ///
+    /// # Examples
+    ///
+    /// Sorting UUIDs the same way Cassandra does:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let mut uuids = [
+    ///     Uuid::parse_str("20616934-4ba2-11e7-8000-010203040506")?,
+    ///     Uuid::parse_str("10616934-4ba2-11e7-8000-010203040506")?,
+    ///     Uuid::parse_str("00000000-4ba3-11e7-8000-010203040506")?,
+    /// ];
+    ///
+    /// uuids.sort_by(Uuid::cassandra_timeuuid_cmp);
+    ///
+    /// assert_eq!(
+    ///     [
+    ///         "10616934-4ba2-11e7-8000-010203040506",
+    ///         "20616934-4ba2-11e7-8000-010203040506",
+    ///         "00000000-4ba3-11e7-8000-010203040506",
+    ///     ],
+    ///     uuids.map(|uuid| uuid.to_string()),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cassandra_timeuuid_cmp(&self, other: &Uuid) -> Ordering {
+        let (a_ticks, _) = timestamp::decode_gregorian_timestamp(self);
+        let (b_ticks, _) = timestamp::decode_gregorian_timestamp(other);
+
+        (self.get_version_num(), a_ticks)
+            .cmp(&(other.get_version_num(), b_ticks))
+            .then_with(|| {
+                let a = self.as_bytes()[8..].iter().map(|b| *b as i8);
+                let b = other.as_bytes()[8..].iter().map(|b| *b as i8);
+
+                a.cmp(b)
+            })
+    }
+
+    /// The lowest `timeuuid` Cassandra and ScyllaDB will consider for a given timestamp.
+    ///
+    /// This is the same value as CQL's `minTimeuuid` function. The timestamp is truncated
+    /// to millisecond precision. The clock sequence and node are set to a sentinel value
+    /// that sorts before any other using [`Uuid::cassandra_timeuuid_cmp`]. Note that the
+    /// sentinel value doesn't preserve the RFC 9562 variant.
+    ///
+    /// The returned UUID is only useful as a query bound and shouldn't be stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// assert_eq!(
+    ///     "f3b49580-52a1-11e7-8080-808080808080",
+    ///     Uuid::cassandra_min_timeuuid(ts).to_string(),
+    /// );
+    /// ```
+    pub const fn cassandra_min_timeuuid(ts: Timestamp) -> Uuid {
+        let ticks = gregorian_ticks_from_millis(ts.unix_millis());
+
+        with_clock_seq_and_node(ticks, MIN_CLOCK_SEQ_AND_NODE)
+    }
+
+    /// The highest `timeuuid` Cassandra and ScyllaDB will consider for a given timestamp.
+    ///
+    /// This is the same value as CQL's `maxTimeuuid` function. The timestamp is truncated
+    /// to millisecond precision, and the returned UUID uses the last 100ns tick within that
+    /// millisecond. The clock sequence and node are set to a sentinel value that sorts after
+    /// any other using [`Uuid::cassandra_timeuuid_cmp`]. Note that the sentinel value doesn't
+    /// preserve the RFC 9562 variant.
+    ///
+    /// The returned UUID is only useful as a query bound and shouldn't be stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// assert_eq!(
+    ///     "f3b4bc8f-52a1-11e7-7f7f-7f7f7f7f7f7f",
+    ///     Uuid::cassandra_max_timeuuid(ts).to_string(),
+    /// );
+    /// ```
+    pub const fn cassandra_max_timeuuid(ts: Timestamp) -> Uuid {
+        let ticks = gregorian_ticks_from_millis(ts.unix_millis().wrapping_add(1)).wrapping_sub(1);
+
+        with_clock_seq_and_node(ticks, MAX_CLOCK_SEQ_AND_NODE)
+    }
+}
+
+const fn gregorian_ticks_from_millis(millis: u64) -> u64 {
+    UUID_TICKS_BETWEEN_EPOCHS.wrapping_add(millis.wrapping_mul(10_000))
+}
+
+const fn with_clock_seq_and_node(ticks: u64, clock_seq_and_node: u64) -> Uuid {
+    let (time_and_version, _) =
+        timestamp::encode_gregorian_timestamp(ticks, 0, &[0; 6]).as_u64_pair();
+
+    Uuid::from_u64_pair(time_and_version, clock_seq_and_node)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{std::vec::Vec, Builder, NoContext, Version};
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_cmp_by_timestamp() {
+        let ts = |ticks| Builder::from_gregorian_timestamp(ticks, 0, &[0; 6]).into_uuid();
+
+        let a = ts(0x01e7_4ba2_2061_6934);
+        let b = ts(0x01e7_4ba2_2061_6935);
+        let c = ts(0x01e7_4ba3_0000_0000);
+
+        // The byte order of these UUIDs doesn't match their timestamps
+        assert!(c < a);
+
+        assert_eq!(Ordering::Less, a.cassandra_timeuuid_cmp(&b));
+        assert_eq!(Ordering::Less, b.cassandra_timeuuid_cmp(&c));
+        assert_eq!(Ordering::Greater, c.cassandra_timeuuid_cmp(&a));
+        assert_eq!(Ordering::Equal, a.cassandra_timeuuid_cmp(&a));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_cmp_signed_bytes() {
+        let a = Uuid::parse_str("20616934-4ba2-11e7-8000-010203040506").unwrap();
+        let b = Uuid::parse_str("20616934-4ba2-11e7-7f00-010203040506").unwrap();
+        let c = Uuid::parse_str("20616934-4ba2-11e7-7f00-810203040506").unwrap();
+
+        // `0x80` is negative when treated as a signed byte
+        assert!(a > b);
+        assert_eq!(Ordering::Less, a.cassandra_timeuuid_cmp(&b));
+        assert_eq!(Ordering::Less, c.cassandra_timeuuid_cmp(&b));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_min_max_timeuuid() {
+        let ts = Timestamp::from_unix(NoContext, 1_496_854_535, 812_946_000);
+
+        let min = Uuid::cassandra_min_timeuuid(ts);
+        let max = Uuid::cassandra_max_timeuuid(ts);
+
+        for uuid in [min, max] {
+            assert_eq!(Some(Version::Mac), uuid.get_version());
+        }
+
+        assert_eq!(
+            (1_496_854_535, 812_000_000),
+            min.get_timestamp().unwrap().to_unix()
+        );
+        assert_eq!(
+            (1_496_854_535, 812_999_900),
+            max.get_timestamp().unwrap().to_unix()
+        );
+
+        let (ticks, _) = ts.to_gregorian();
+
+        let mut within = Vec::new();
+        for clock_seq in [0, 0x2000, 0x3fff] {
+            for node in [[0; 6], [0x80; 6], [0xff; 6]] {
+                within.push(Builder::from_gregorian_timestamp(ticks, clock_seq, &node).into_uuid());
+            }
+        }
+
+        for uuid in within {
+            assert_eq!(Ordering::Less, min.cassandra_timeuuid_cmp(&uuid));
+            assert_eq!(Ordering::Greater, max.cassandra_timeuuid_cmp(&uuid));
+        }
+
+        // The neighbouring milliseconds fall outside the bounds
+        let before = Timestamp::from_unix(NoContext, 1_496_854_535, 811_999_999);
+        let after = Timestamp::from_unix(NoContext, 1_496_854_535, 813_000_000);
+
+        assert_eq!(
+            Ordering::Less,
+            Uuid::cassandra_max_timeuuid(before).cassandra_timeuuid_cmp(&min)
+        );
+        assert_eq!(
+            Ordering::Greater,
+            Uuid::cassandra_min_timeuuid(after).cassandra_timeuuid_cmp(&max)
+        );
+    }
+}
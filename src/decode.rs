@@ -0,0 +1,686 @@
+//! Structured views of the fields in a UUID.
+//!
+//! [`Uuid::decode`] splits a UUID into the fields defined for its version
+//! in RFC 9562. [`Uuid::describe`] formats those fields for debugging and
+//! log output.
+//!
+//! # References
+//!
+//! * [UUID Version Layouts in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-5)
+
+use crate::{
+    std::fmt,
+    timestamp::{self, UUID_TICKS_BETWEEN_EPOCHS},
+    Timestamp, Uuid, Variant,
+};
+
+/// The fields of a UUID, decoded according to its version.
+///
+/// Fields are named after their definitions in RFC 9562. Fields that are
+/// narrower than their Rust type are stored in the least significant bits.
+/// The version and variant bits aren't included in any field.
+///
+/// This type is returned by [`Uuid::decode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Decoded {
+    /// The "nil" (all zeros) UUID.
+    Nil,
+    /// Version 1: Timestamp and node ID.
+    Mac {
+        /// The 60-bit number of 100ns ticks since 1582-10-15.
+        ticks: u64,
+        /// The 14-bit clock sequence.
+        clock_seq: u16,
+        /// The 48-bit node ID.
+        node_id: [u8; 6],
+    },
+    /// Version 2: DCE Security.
+    Dce {
+        /// The 60-bit number of 100ns ticks since 1582-10-15.
+        ///
+        /// The least significant 32 bits of the timestamp are replaced by the
+        /// local identifier, so they're always zero.
+        ticks: u64,
+        /// The 6-bit clock sequence.
+        clock_seq: u8,
+        /// The local domain, such as `0` for a POSIX UID.
+        local_domain: u8,
+        /// The local identifier within the domain.
+        local_id: u32,
+        /// The 48-bit node ID.
+        node_id: [u8; 6],
+    },
+    /// Version 3: MD5 hash.
+    Md5 {
+        /// The first 48 bits of the hash.
+        md5_high: u64,
+        /// The next 12 bits of the hash.
+        md5_mid: u16,
+        /// The final 62 bits of the hash.
+        md5_low: u64,
+    },
+    /// Version 4: Random.
+    Random {
+        /// The first 48 random bits.
+        random_a: u64,
+        /// The next 12 random bits.
+        random_b: u16,
+        /// The final 62 random bits.
+        random_c: u64,
+    },
+    /// Version 5: SHA-1 hash.
+    Sha1 {
+        /// The first 48 bits of the hash.
+        sha1_high: u64,
+        /// The next 12 bits of the hash.
+        sha1_mid: u16,
+        /// The final 62 bits of the hash.
+        sha1_low: u64,
+    },
+    /// Version 6: Sortable Timestamp and node ID.
+    SortMac {
+        /// The 60-bit number of 100ns ticks since 1582-10-15.
+        ticks: u64,
+        /// The 14-bit clock sequence.
+        clock_seq: u16,
+        /// The 48-bit node ID.
+        node_id: [u8; 6],
+    },
+    /// Version 7: Timestamp and random.
+    SortRand {
+        /// The 48-bit number of milliseconds since the Unix epoch.
+        unix_ts_ms: u64,
+        /// The 12 bits following the timestamp.
+        ///
+        /// These bits may contain a counter or additional timestamp precision.
+        rand_a: u16,
+        /// The final 62 bits.
+        rand_b: u64,
+    },
+    /// Version 8: Custom.
+    Custom {
+        /// The first 48 bits.
+        custom_a: u64,
+        /// The next 12 bits.
+        custom_b: u16,
+        /// The final 62 bits.
+        custom_c: u64,
+    },
+    /// The "max" (all ones) UUID.
+    Max,
+    /// Legacy NCS variant: Timestamp and host.
+    Ncs {
+        /// The 48-bit number of 4µs ticks since 1980-01-01.
+        ticks: u64,
+        /// The 7-bit address family.
+        family: u8,
+        /// The 56-bit host ID.
+        host_id: [u8; 7],
+    },
+    /// A UUID with a variant or version that doesn't have a known layout.
+    Other,
+}
+
+impl Uuid {
+    /// Decode the fields of this UUID according to its version.
+    ///
+    /// Versions 1 through 8 are only decoded for UUIDs with the RFC 9562 variant.
+    /// UUIDs with the legacy NCS variant and zeroed reserved bits are decoded as
+    /// [`Decoded::Ncs`]. Any other
+    /// UUID, apart from the nil and max UUIDs, will return [`Decoded::Other`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, decode::Decoded};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// assert_eq!(
+    ///     Decoded::SortRand {
+    ///         unix_ts_ms: 0x017f22e279b0,
+    ///         rand_a: 0xcc3,
+    ///         rand_b: 0x18c4dc0c0c07398f,
+    ///     },
+    ///     uuid.decode(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn decode(&self) -> Decoded {
+        if self.is_nil() {
+            return Decoded::Nil;
+        }
+
+        if self.is_max() {
+            return Decoded::Max;
+        }
+
+        match self.get_variant() {
+            Variant::RFC4122 => (),
+            Variant::NCS if self.is_ncs_layout() => {
+                let bytes = self.as_bytes();
+
+                return Decoded::Ncs {
+                    ticks: timestamp::decode_ncs_timestamp(self),
+                    family: bytes[8],
+                    host_id: [
+                        bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+                    ],
+                };
+            }
+            _ => return Decoded::Other,
+        }
+
+        let (a, b, c) = split_fields(self);
+
+        match self.get_version_num() {
+            1 => {
+                let (ticks, clock_seq) = timestamp::decode_gregorian_timestamp(self);
+
+                Decoded::Mac {
+                    ticks,
+                    clock_seq,
+                    node_id: node_id(self),
+                }
+            }
+            2 => {
+                let (ticks, _) = timestamp::decode_gregorian_timestamp(self);
+                let bytes = self.as_bytes();
+
+                Decoded::Dce {
+                    ticks: ticks & !0xFFFF_FFFF,
+                    clock_seq: bytes[8] & 0x3F,
+                    local_domain: bytes[9],
+                    local_id: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+                    node_id: node_id(self),
+                }
+            }
+            3 => Decoded::Md5 {
+                md5_high: a,
+                md5_mid: b,
+                md5_low: c,
+            },
+            4 => Decoded::Random {
+                random_a: a,
+                random_b: b,
+                random_c: c,
+            },
+            5 => Decoded::Sha1 {
+                sha1_high: a,
+                sha1_mid: b,
+                sha1_low: c,
+            },
+            6 => {
+                let (ticks, clock_seq) = timestamp::decode_sorted_gregorian_timestamp(self);
+
+                Decoded::SortMac {
+                    ticks,
+                    clock_seq,
+                    node_id: node_id(self),
+                }
+            }
+            7 => Decoded::SortRand {
+                unix_ts_ms: a,
+                rand_a: b,
+                rand_b: c,
+            },
+            8 => Decoded::Custom {
+                custom_a: a,
+                custom_b: b,
+                custom_c: c,
+            },
+            _ => Decoded::Other,
+        }
+    }
+
+    /// Get a [`Describe`] formatter.
+    ///
+    /// This formatter writes a short, human-readable summary of the fields in
+    /// the UUID. The output is intended for debugging and log messages, and isn't
+    /// guaranteed to be stable between releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// assert_eq!(
+    ///     "v7 2022-02-22T19:22:22.000Z rand=330d8c4dc0c0c07398f",
+    ///     uuid.describe().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn describe(self) -> Describe {
+        Describe(self)
+    }
+}
+
+/// Format a [`Uuid`] as a human-readable summary of its fields, like
+/// `v7 2022-02-22T19:22:22.000Z rand=330d8c4dc0c0c07398f`.
+///
+/// Timestamps are written in UTC using RFC 3339. Other fields are written
+/// in hex, with the version and variant bits removed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Describe(Uuid);
+
+impl Describe {
+    /// Creates a [`Describe`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Describe(uuid)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Describe`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for Describe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.decode() {
+            Decoded::Nil => write!(f, "nil"),
+            Decoded::Max => write!(f, "max"),
+            Decoded::Mac {
+                ticks,
+                clock_seq,
+                node_id,
+            } => {
+                write!(f, "v1 ")?;
+                write_gregorian(f, ticks)?;
+                write!(f, " clock_seq={:04x} node=", clock_seq)?;
+                write_node_id(f, &node_id)
+            }
+            Decoded::Dce {
+                ticks,
+                clock_seq,
+                local_domain,
+                local_id,
+                node_id,
+            } => {
+                write!(f, "v2 ")?;
+                write_gregorian(f, ticks)?;
+                write!(
+                    f,
+                    " clock_seq={:02x} domain={} id={} node=",
+                    clock_seq, local_domain, local_id
+                )?;
+                write_node_id(f, &node_id)
+            }
+            Decoded::Md5 {
+                md5_high,
+                md5_mid,
+                md5_low,
+            } => write!(f, "v3 md5={:031x}", join(md5_high, md5_mid, md5_low)),
+            Decoded::Random {
+                random_a,
+                random_b,
+                random_c,
+            } => write!(f, "v4 rand={:031x}", join(random_a, random_b, random_c)),
+            Decoded::Sha1 {
+                sha1_high,
+                sha1_mid,
+                sha1_low,
+            } => write!(f, "v5 sha1={:031x}", join(sha1_high, sha1_mid, sha1_low)),
+            Decoded::SortMac {
+                ticks,
+                clock_seq,
+                node_id,
+            } => {
+                write!(f, "v6 ")?;
+                write_gregorian(f, ticks)?;
+                write!(f, " clock_seq={:04x} node=", clock_seq)?;
+                write_node_id(f, &node_id)
+            }
+            Decoded::SortRand {
+                unix_ts_ms,
+                rand_a,
+                rand_b,
+            } => {
+                write!(f, "v7 ")?;
+                write_utc(f, (unix_ts_ms / 1000) as i64, (unix_ts_ms % 1000) as u32, 3)?;
+                write!(
+                    f,
+                    " rand={:019x}",
+                    ((rand_a as u128) << 62) | rand_b as u128
+                )
+            }
+            Decoded::Custom {
+                custom_a,
+                custom_b,
+                custom_c,
+            } => write!(f, "v8 custom={:031x}", join(custom_a, custom_b, custom_c)),
+            Decoded::Ncs {
+                ticks,
+                family,
+                host_id,
+                ..
+            } => {
+                write!(f, "ncs ")?;
+                write_ncs(f, ticks)?;
+                write!(f, " family={} host=", family)?;
+                write_host_id(f, &host_id)
+            }
+            Decoded::Other => write!(f, "{} {}", self.0.get_variant(), self.0),
+        }
+    }
+}
+
+// Split a UUID into the 48, 12, and 62 bit fields surrounding the version and variant
+const fn split_fields(uuid: &Uuid) -> (u64, u16, u64) {
+    let (high, low) = uuid.as_u64_pair();
+
+    (high >> 16, (high & 0x0FFF) as u16, low & (u64::MAX >> 2))
+}
+
+const fn node_id(uuid: &Uuid) -> [u8; 6] {
+    let bytes = uuid.as_bytes();
+
+    [
+        bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+    ]
+}
+
+fn join(a: u64, b: u16, c: u64) -> u128 {
+    ((a as u128) << 74) | ((b as u128) << 62) | c as u128
+}
+
+fn write_node_id(f: &mut fmt::Formatter<'_>, node_id: &[u8; 6]) -> fmt::Result {
+    write!(
+        f,
+        "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+        node_id[0], node_id[1], node_id[2], node_id[3], node_id[4], node_id[5]
+    )
+}
+
+fn write_host_id(f: &mut fmt::Formatter<'_>, host_id: &[u8; 7]) -> fmt::Result {
+    write!(
+        f,
+        "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+        host_id[0], host_id[1], host_id[2], host_id[3], host_id[4], host_id[5], host_id[6]
+    )
+}
+
+fn write_ncs(f: &mut fmt::Formatter<'_>, ticks: u64) -> fmt::Result {
+    let (secs, nanos) = Timestamp::from_ncs_time(ticks).to_unix();
+
+    write_utc(f, secs as i64, nanos / 1_000, 6)
+}
+
+fn write_gregorian(f: &mut fmt::Formatter<'_>, ticks: u64) -> fmt::Result {
+    let secs = (ticks / 10_000_000) as i64 - (UUID_TICKS_BETWEEN_EPOCHS / 10_000_000) as i64;
+
+    write_utc(f, secs, (ticks % 10_000_000) as u32, 7)
+}
+
+// Write a Unix timestamp as an RFC 3339 date and time in UTC
+//
+// The conversion from days to a civil date is based on Howard Hinnant's `civil_from_days`
+// See: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn write_utc(f: &mut fmt::Formatter<'_>, secs: i64, fraction: u32, digits: usize) -> fmt::Result {
+    let days = secs.div_euclid(86_400);
+    let secs_of_day = secs.rem_euclid(86_400);
+
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    write!(
+        f,
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:0digits$}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60,
+        fraction,
+        digits = digits
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::string::ToString;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_decode_nil_max() {
+        assert_eq!(Decoded::Nil, Uuid::nil().decode());
+        assert_eq!(Decoded::Max, Uuid::max().decode());
+
+        assert_eq!("nil", Uuid::nil().describe().to_string());
+        assert_eq!("max", Uuid::max().describe().to_string());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_decode_timestamp_node() {
+        // Examples from RFC 9562 Appendix A
+        let v1 = Uuid::parse_str("c232ab00-9414-11ec-b3c8-9f6bdeced846").unwrap();
+        let v6 = Uuid::parse_str("1ec9414c-232a-6b00-b3c8-9f6bdeced846").unwrap();
+
+        let expected = (
+            0x1ec9414c232ab00,
+            0x33c8,
+            [0x9f, 0x6b, 0xde, 0xce, 0xd8, 0x46],
+        );
+
+        assert_eq!(
+            Decoded::Mac {
+                ticks: expected.0,
+                clock_seq: expected.1,
+                node_id: expected.2,
+            },
+            v1.decode()
+        );
+        assert_eq!(
+            Decoded::SortMac {
+                ticks: expected.0,
+                clock_seq: expected.1,
+                node_id: expected.2,
+            },
+            v6.decode()
+        );
+
+        assert_eq!(
+            "v1 2022-02-22T19:22:22.0000000Z clock_seq=33c8 node=9f:6b:de:ce:d8:46",
+            v1.describe().to_string()
+        );
+        assert_eq!(
+            "v6 2022-02-22T19:22:22.0000000Z clock_seq=33c8 node=9f:6b:de:ce:d8:46",
+            v6.describe().to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_decode_dce() {
+        let uuid = Uuid::parse_str("000003e8-9414-21ec-b300-9f6bdeced846").unwrap();
+
+        assert_eq!(
+            Decoded::Dce {
+                ticks: 0x1ec941400000000,
+                clock_seq: 0x33,
+                local_domain: 0,
+                local_id: 1000,
+                node_id: [0x9f, 0x6b, 0xde, 0xce, 0xd8, 0x46],
+            },
+            uuid.decode()
+        );
+
+        assert!(uuid
+            .describe()
+            .to_string()
+            .ends_with(" clock_seq=33 domain=0 id=1000 node=9f:6b:de:ce:d8:46"));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_decode_hash_random_custom() {
+        // Examples from RFC 9562 Appendix A
+        let v3 = Uuid::parse_str("5df41881-3aed-3515-88a7-2f4a814cf09e").unwrap();
+        let v4 = Uuid::parse_str("919108f7-52d1-4320-9bac-f847db4148a8").unwrap();
+        let v5 = Uuid::parse_str("2ed6657d-e927-568b-95e1-2665a8aea6a2").unwrap();
+        let v8 = Uuid::parse_str("2489e9ad-2ee2-8e00-8ec9-32d5f69181c0").unwrap();
+
+        assert_eq!(
+            Decoded::Md5 {
+                md5_high: 0x5df418813aed,
+                md5_mid: 0x515,
+                md5_low: 0x08a72f4a814cf09e,
+            },
+            v3.decode()
+        );
+        assert_eq!(
+            Decoded::Random {
+                random_a: 0x919108f752d1,
+                random_b: 0x320,
+                random_c: 0x1bacf847db4148a8,
+            },
+            v4.decode()
+        );
+        assert_eq!(
+            Decoded::Sha1 {
+                sha1_high: 0x2ed6657de927,
+                sha1_mid: 0x68b,
+                sha1_low: 0x15e12665a8aea6a2,
+            },
+            v5.decode()
+        );
+        assert_eq!(
+            Decoded::Custom {
+                custom_a: 0x2489e9ad2ee2,
+                custom_b: 0xe00,
+                custom_c: 0x0ec932d5f69181c0,
+            },
+            v8.decode()
+        );
+
+        assert_eq!(
+            "v3 md5=177d06204ebb54548a72f4a814cf09e",
+            v3.describe().to_string()
+        );
+        assert_eq!(
+            "v8 custom=09227a6b4bb8b800ec932d5f69181c0",
+            v8.describe().to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_decode_sort_rand() {
+        // Example from RFC 9562 Appendix A
+        let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap();
+
+        assert_eq!(
+            Decoded::SortRand {
+                unix_ts_ms: 0x017f22e279b0,
+                rand_a: 0xcc3,
+                rand_b: 0x18c4dc0c0c07398f,
+            },
+            uuid.decode()
+        );
+
+        assert_eq!(
+            "v7 2022-02-22T19:22:22.000Z rand=330d8c4dc0c0c07398f",
+            uuid.describe().to_string()
+        );
+
+        // The max 48-bit timestamp is formatted with a 5 digit year
+        let max = crate::Builder::from_unix_timestamp_millis(u64::MAX >> 16, &[0; 10]).into_uuid();
+
+        assert!(max
+            .describe()
+            .to_string()
+            .starts_with("v7 10889-08-02T05:31:50.655Z"));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_decode_ncs() {
+        let uuid = Uuid::parse_str("333a2276-0000-0000-0d00-00809c000000").unwrap();
+
+        assert_eq!(
+            Decoded::Ncs {
+                ticks: 0x333a_2276_0000,
+                family: 13,
+                host_id: [0x00, 0x00, 0x80, 0x9c, 0x00, 0x00, 0x00],
+            },
+            uuid.decode()
+        );
+
+        assert_eq!(
+            "ncs 1987-02-20T15:05:17.113344Z family=13 host=00:00:80:9c:00:00:00",
+            uuid.describe().to_string()
+        );
+
+        // The NCS variant alone isn't enough; the reserved bits must also be zero
+        let unreserved = Uuid::from_u128(0x1ec9414c_232a_4b00_33c8_9f6bdeced846);
+
+        assert_eq!(Decoded::Other, unreserved.decode());
+        assert_eq!(
+            "NCS 1ec9414c-232a-4b00-33c8-9f6bdeced846",
+            unreserved.describe().to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_decode_other() {
+        let microsoft = Uuid::parse_str("67e55044-10b1-426f-c247-bb680e5fe0c8").unwrap();
+        let unknown = Uuid::parse_str("67e55044-10b1-926f-9247-bb680e5fe0c8").unwrap();
+
+        assert_eq!(Decoded::Other, microsoft.decode());
+        assert_eq!(Decoded::Other, unknown.decode());
+
+        assert_eq!(
+            "Microsoft 67e55044-10b1-426f-c247-bb680e5fe0c8",
+            microsoft.describe().to_string()
+        );
+    }
+}
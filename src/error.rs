@@ -1,4 +1,4 @@
-use crate::std::fmt;
+use crate::{std::fmt, Variant};
 
 /// A general error that can occur when working with UUIDs.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -32,13 +32,105 @@ pub(crate) enum ErrorKind {
     ParseInvalidUTF8,
     /// Some other parsing error occurred.
     ParseOther,
+    /// A base64url [`Uuid`] didn't contain 22 characters.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    Base64UrlLength { len: usize },
+    /// A base32 [`Uuid`] didn't contain 26 characters.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    Base32Length { len: usize },
+    /// A base32 [`Uuid`] encoded a value larger than 128 bits.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    Base32Overflow,
+    /// A decimal [`Uuid`] was empty or longer than 39 digits.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    DecimalLength { len: usize },
+    /// A decimal [`Uuid`] encoded a value larger than 128 bits.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    DecimalOverflow,
+    /// An OID [`Uuid`] didn't start with the `2.25` arc.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    OidPrefix,
+    /// An OID [`Uuid`] had an empty last arc, or one longer than 39 digits.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    OidLength { len: usize },
+    /// The last arc of an OID [`Uuid`] had a leading zero.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    OidLeadingZero,
+    /// A DER encoded OID [`Uuid`] wasn't valid.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    OidDer,
+    /// A proquint [`Uuid`] didn't contain 47 characters.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ProquintLength { len: usize },
+    /// A radix alphabet wasn't made up of distinct printable ASCII characters.
+    RadixAlphabet,
+    /// A radix [`Uuid`] was empty or longer than the width of its alphabet.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    RadixLength { len: usize, max: usize },
+    /// A radix [`Uuid`] encoded a value larger than 128 bits.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    RadixOverflow,
+    /// A base32 [`Uuid`] with a check symbol didn't contain 27 symbols.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    Base32CheckLength { len: usize },
+    /// A base32 [`Uuid`] was well-formed, but its check symbol didn't match.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    Base32CheckMismatch { expected: char, found: char },
+    /// A custom formatted [`Uuid`] didn't have the length of its spec.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    CustomLength { expected: usize, len: usize },
+    /// The groups of a custom format spec were empty or didn't add up to 32 hex digits.
+    CustomGroups,
+    /// A C struct initializer [`Uuid`] ended before its closing brace.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    CStructIncomplete,
+    /// An abbreviated [`Uuid`] didn't contain between 1 and 32 hex digits.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    AbbrevLength { len: usize },
+    /// A TypeID prefix wasn't valid.
+    TypeIdPrefix,
     /// The UUID is nil.
     Nil,
+    /// The UUID isn't the expected version.
+    UnexpectedVersion { expected: usize, found: usize },
+    /// The UUID has the expected version number, but not the RFC 9562 variant.
+    UnexpectedVariant { found: Variant },
+    /// A ULID didn't have the version and variant bits of a version 7 UUID.
+    UlidNotV7,
     /// A system time was invalid.
     #[cfg(feature = "std")]
     InvalidSystemTime(&'static str),
 }
 
+impl Error {
+    /// Whether the error is from a well-formed string whose checksum didn't match.
+    ///
+    /// This can be used to tell a mistyped [`Base32Check`] string apart from one
+    /// that's malformed.
+    ///
+    /// [`Base32Check`]: crate::fmt::Base32Check
+    pub const fn is_checksum_mismatch(&self) -> bool {
+        matches!(self.0, ErrorKind::Base32CheckMismatch { .. })
+    }
+}
+
 /// A string that is guaranteed to fail to parse to a [`Uuid`].
 ///
 /// This type acts as a lightweight error indicator, suggesting
@@ -163,7 +255,129 @@ impl fmt::Display for Error {
                 )
             }
             ErrorKind::ParseInvalidUTF8 => write!(f, "non-UTF8 input"),
+            ErrorKind::Base64UrlLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected length 22 for base64url format, found {}",
+                    len
+                )
+            }
+            ErrorKind::Base32Length { len } => {
+                write!(
+                    f,
+                    "invalid length: expected length 26 for base32 format, found {}",
+                    len
+                )
+            }
+            ErrorKind::Base32Overflow => write!(
+                f,
+                "invalid base32: the first character must be between `0` and `7`"
+            ),
+            ErrorKind::DecimalLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected between 1 and 39 digits for decimal format, found {}",
+                    len
+                )
+            }
+            ErrorKind::DecimalOverflow => write!(
+                f,
+                "invalid decimal: the encoded value is larger than 128 bits"
+            ),
+            ErrorKind::OidPrefix => write!(f, "invalid OID: expected a prefix of `2.25.`"),
+            ErrorKind::OidLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected between 6 and 44 characters for OID format, found {}",
+                    len
+                )
+            }
+            ErrorKind::OidLeadingZero => {
+                write!(f, "invalid OID: the last arc can't have leading zeros")
+            }
+            ErrorKind::OidDer => write!(
+                f,
+                "invalid DER: expected an OID with a single arc under `2.25`"
+            ),
+            ErrorKind::ProquintLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected length 47 for proquint format, found {}",
+                    len
+                )
+            }
+            ErrorKind::RadixAlphabet => write!(
+                f,
+                "invalid radix alphabet: expected between 2 and 94 distinct printable ASCII characters"
+            ),
+            ErrorKind::RadixLength { len, max } => {
+                write!(
+                    f,
+                    "invalid length: expected between 1 and {} characters for radix format, found {}",
+                    max, len
+                )
+            }
+            ErrorKind::RadixOverflow => write!(
+                f,
+                "invalid radix string: the encoded value is larger than 128 bits"
+            ),
+            ErrorKind::Base32CheckLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected 27 symbols for checked base32 format, found {}",
+                    len
+                )
+            }
+            ErrorKind::Base32CheckMismatch { expected, found } => write!(
+                f,
+                "checksum mismatch: expected check symbol `{}`, found `{}`",
+                expected, found
+            ),
+            ErrorKind::CustomLength { expected, len } => {
+                write!(
+                    f,
+                    "invalid length: expected length {} for custom format, found {}",
+                    expected, len
+                )
+            }
+            ErrorKind::CustomGroups => write!(
+                f,
+                "invalid custom format groups: expected non-empty groups adding up to 32 hex digits"
+            ),
+            ErrorKind::CStructIncomplete => write!(
+                f,
+                "invalid C struct initializer: unexpected end of input"
+            ),
+            ErrorKind::AbbrevLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected between 1 and 32 hex digits for abbreviated UUID, found {}",
+                    len
+                )
+            }
+            ErrorKind::TypeIdPrefix => write!(
+                f,
+                "invalid TypeID prefix: expected at most 63 lowercase letters or underscores, not starting or ending with an underscore"
+            ),
             ErrorKind::Nil => write!(f, "the UUID is nil"),
+            ErrorKind::UnexpectedVersion { expected, found } => {
+                write!(
+                    f,
+                    "unexpected UUID version: expected {}, found {}",
+                    expected, found
+                )
+            }
+            ErrorKind::UnexpectedVariant { found } => {
+                write!(
+                    f,
+                    "unexpected UUID variant: expected RFC 9562, found {}",
+                    found
+                )
+            }
+            ErrorKind::UlidNotV7 => write!(
+                f,
+                "the ULID can't be converted to a version 7 UUID without overwriting its version and variant bits"
+            ),
             ErrorKind::ParseOther => write!(f, "failed to parse a UUID"),
             #[cfg(feature = "std")]
             ErrorKind::InvalidSystemTime(ref e) => {
@@ -1,4 +1,4 @@
-use crate::{non_nil::NonNilUuid, Builder, Uuid};
+use crate::{non_nil::NonNilUuid, typed::TypedUuid, Builder, Uuid};
 
 use arbitrary::{Arbitrary, Unstructured};
 
@@ -27,6 +27,16 @@ impl arbitrary::Arbitrary<'_> for NonNilUuid {
     }
 }
 
+impl<T: ?Sized> Arbitrary<'_> for TypedUuid<T> {
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self> {
+        Uuid::arbitrary(u).map(TypedUuid::from_uuid)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Uuid::size_hint(depth)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,4 +74,13 @@ mod tests {
         assert_eq!(Variant::RFC4122, uuid.get_variant());
         assert!(!uuid.is_nil());
     }
+
+    #[test]
+    fn test_arbitrary_typed() {
+        let mut bytes = Unstructured::new(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let typed = TypedUuid::<str>::arbitrary(&mut bytes).unwrap();
+
+        assert_eq!(Some(Version::Random), typed.untyped().get_version());
+    }
 }
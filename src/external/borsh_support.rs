@@ -1,3 +1,67 @@
+use crate::{
+    non_nil::NonNilUuid, typed::TypedUuid, Uuid, UuidV1, UuidV3, UuidV4, UuidV5, UuidV6, UuidV7,
+    UuidV8,
+};
+
+use borsh::{
+    io::{self, Read, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+impl BorshSerialize for NonNilUuid {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.as_uuid().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for NonNilUuid {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let uuid = Uuid::deserialize_reader(reader)?;
+
+        NonNilUuid::new(uuid)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "expected a non-nil UUID"))
+    }
+}
+
+impl<T: ?Sized> BorshSerialize for TypedUuid<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.as_uuid().serialize(writer)
+    }
+}
+
+impl<T: ?Sized> BorshDeserialize for TypedUuid<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        Uuid::deserialize_reader(reader).map(TypedUuid::from_uuid)
+    }
+}
+
+macro_rules! impl_versioned {
+    ($($name:ident),*) => {
+        $(
+            impl BorshSerialize for $name {
+                fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    Uuid::from(*self).serialize(writer)
+                }
+            }
+
+            impl BorshDeserialize for $name {
+                fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+                    let uuid = Uuid::deserialize_reader(reader)?;
+
+                    $name::from_uuid(uuid).ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            concat!("expected a ", stringify!($name)),
+                        )
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_versioned!(UuidV1, UuidV3, UuidV4, UuidV5, UuidV6, UuidV7, UuidV8);
+
 #[cfg(test)]
 mod borsh_tests {
     use crate::Uuid;
@@ -20,4 +84,50 @@ mod borsh_tests {
         let deserialized = borsh::from_slice::<Uuid>(&uuid_bytes).unwrap().to_string();
         assert_eq!(uuid_str, deserialized);
     }
+
+    #[test]
+    fn test_non_nil() {
+        let uuid_str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+        let uuid = Uuid::parse_str(uuid_str).unwrap();
+        let non_nil = crate::NonNilUuid::new(uuid).unwrap();
+
+        let borsh_bytes = borsh::to_vec(&non_nil).unwrap();
+        assert_eq!(uuid.as_bytes().to_vec(), borsh_bytes);
+
+        let deserialized = borsh::from_slice::<crate::NonNilUuid>(&borsh_bytes).unwrap();
+        assert_eq!(non_nil, deserialized);
+
+        let none = borsh::from_slice::<Option<crate::NonNilUuid>>(&[0]).unwrap();
+        assert_eq!(None, none);
+
+        assert!(borsh::from_slice::<crate::NonNilUuid>(&[0; 16]).is_err());
+    }
+
+    #[test]
+    fn test_versioned() {
+        let uuid_str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+        let uuid = Uuid::parse_str(uuid_str).unwrap();
+        let v4 = crate::UuidV4::try_from(uuid).unwrap();
+
+        let borsh_bytes = borsh::to_vec(&v4).unwrap();
+        assert_eq!(uuid.as_bytes().to_vec(), borsh_bytes);
+
+        let deserialized = borsh::from_slice::<crate::UuidV4>(&borsh_bytes).unwrap();
+        assert_eq!(v4, deserialized);
+
+        assert!(borsh::from_slice::<crate::UuidV7>(&borsh_bytes).is_err());
+    }
+
+    #[test]
+    fn test_typed() {
+        let uuid_str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+        let uuid = Uuid::parse_str(uuid_str).unwrap();
+        let typed = crate::TypedUuid::<str>::from_uuid(uuid);
+
+        let borsh_bytes = borsh::to_vec(&typed).unwrap();
+        assert_eq!(uuid.as_bytes().to_vec(), borsh_bytes);
+
+        let deserialized = borsh::from_slice::<crate::TypedUuid<str>>(&borsh_bytes).unwrap();
+        assert_eq!(typed, deserialized);
+    }
 }
@@ -0,0 +1,74 @@
+use crate::{non_nil::NonNilUuid, typed::TypedUuid, Uuid};
+
+use bytemuck::{
+    CheckedBitPattern, NoUninit, Pod, PodInOption, TransparentWrapper, Zeroable, ZeroableInOption,
+};
+
+// SAFETY: `TypedUuid<T>` is a `repr(transparent)` wrapper around `Uuid`.
+// The tag is only used in a zero-sized `PhantomData`
+unsafe impl<T: ?Sized> Zeroable for TypedUuid<T> {}
+unsafe impl<T: ?Sized + 'static> Pod for TypedUuid<T> {}
+unsafe impl<T: ?Sized> TransparentWrapper<Uuid> for TypedUuid<T> {}
+
+// SAFETY: `NonNilUuid` is a `repr(transparent)` wrapper around `NonZeroU128`,
+// so it has no padding and `None` is represented as all zeroes
+unsafe impl NoUninit for NonNilUuid {}
+unsafe impl ZeroableInOption for NonNilUuid {}
+unsafe impl PodInOption for NonNilUuid {}
+
+// SAFETY: Any bit pattern besides all zeroes is a valid `NonNilUuid`
+unsafe impl CheckedBitPattern for NonNilUuid {
+    type Bits = u128;
+
+    fn is_valid_bit_pattern(bits: &u128) -> bool {
+        *bits != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::some_uuid_v4;
+
+    #[test]
+    fn test_typed_cast() {
+        let uuid = some_uuid_v4();
+        let typed = TypedUuid::<str>::wrap(uuid);
+
+        assert_eq!(uuid, typed.untyped());
+        assert_eq!(uuid.as_bytes(), bytemuck::bytes_of(&typed));
+        assert_eq!(
+            typed,
+            *bytemuck::from_bytes::<TypedUuid<str>>(uuid.as_bytes())
+        );
+    }
+
+    #[test]
+    fn test_non_nil_cast() {
+        let uuid = some_uuid_v4();
+        let non_nil = NonNilUuid::new(uuid);
+
+        // The bytes of a `NonNilUuid` are the same as the `Uuid` it wraps
+        assert_eq!(uuid.as_bytes(), bytemuck::bytes_of(&non_nil));
+        assert_eq!(
+            non_nil,
+            bytemuck::pod_read_unaligned::<Option<NonNilUuid>>(uuid.as_bytes())
+        );
+
+        assert_eq!(None, <Option<NonNilUuid> as Zeroable>::zeroed());
+        assert_eq!(
+            None,
+            bytemuck::pod_read_unaligned::<Option<NonNilUuid>>(Uuid::nil().as_bytes())
+        );
+
+        assert_eq!(
+            non_nil,
+            bytemuck::checked::try_pod_read_unaligned::<NonNilUuid>(uuid.as_bytes()).ok()
+        );
+        assert!(
+            bytemuck::checked::try_pod_read_unaligned::<NonNilUuid>(Uuid::nil().as_bytes())
+                .is_err()
+        );
+    }
+}
@@ -13,10 +13,11 @@ use core::marker::PhantomData;
 
 use crate::{
     error::*,
-    fmt::{Braced, Hyphenated, Simple, Urn},
+    fmt::{Base32, Base64Url, Braced, Hyphenated, Simple, TypeId, Urn},
     non_nil::NonNilUuid,
     std::fmt,
-    Bytes, Uuid,
+    typed::TypedUuid,
+    Bytes, Uuid, UuidV1, UuidV3, UuidV4, UuidV5, UuidV6, UuidV7, UuidV8,
 };
 use serde_core::{
     de::{self, Error as _},
@@ -66,6 +67,18 @@ impl Serialize for Braced {
     }
 }
 
+impl Serialize for Base32 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.encode_lower(&mut Uuid::encode_buffer()))
+    }
+}
+
+impl Serialize for Base64Url {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.encode(&mut Uuid::encode_buffer()))
+    }
+}
+
 struct UuidReadableVisitor<T> {
     expecting: &'static str,
     _marker: PhantomData<T>,
@@ -267,6 +280,52 @@ impl<'de> Deserialize<'de> for Urn {
     }
 }
 
+impl UuidDeserialize for Base32 {
+    fn from_str(formatted: &str) -> Result<Self, Error> {
+        formatted.parse()
+    }
+
+    fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Uuid::from_slice(bytes)?.into())
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, Error> {
+        Ok(Uuid::from_bytes(bytes).into())
+    }
+}
+
+impl<'de> Deserialize<'de> for Base32 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(UuidReadableVisitor {
+            expecting: "a UUID string in the base32 format",
+            _marker: PhantomData::<Base32>,
+        })
+    }
+}
+
+impl UuidDeserialize for Base64Url {
+    fn from_str(formatted: &str) -> Result<Self, Error> {
+        formatted.parse()
+    }
+
+    fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Uuid::from_slice(bytes)?.into())
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, Error> {
+        Ok(Uuid::from_bytes(bytes).into())
+    }
+}
+
+impl<'de> Deserialize<'de> for Base64Url {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(UuidReadableVisitor {
+            expecting: "a UUID string in the base64url format",
+            _marker: PhantomData::<Base64Url>,
+        })
+    }
+}
+
 impl<'de> Deserialize<'de> for NonNilUuid {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -280,6 +339,66 @@ impl<'de> Deserialize<'de> for NonNilUuid {
     }
 }
 
+impl<T: ?Sized> Serialize for TypedUuid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_uuid().serialize(serializer)
+    }
+}
+
+impl<'de, T: ?Sized> Deserialize<'de> for TypedUuid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Uuid::deserialize(deserializer).map(TypedUuid::from_uuid)
+    }
+}
+
+impl Serialize for TypeId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.encode(&mut [0; TypeId::MAX_LENGTH]))
+    }
+}
+
+impl<'de> Deserialize<'de> for TypeId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TypeIdVisitor;
+
+        impl<'vi> de::Visitor<'vi> for TypeIdVisitor {
+            type Value = TypeId;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a TypeID string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<TypeId, E> {
+                value.parse().map_err(de_error)
+            }
+        }
+
+        deserializer.deserialize_str(TypeIdVisitor)
+    }
+}
+
+macro_rules! impl_versioned {
+    ($($name:ident),*) => {
+        $(
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    Uuid::from(*self).serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let uuid = Uuid::deserialize(deserializer)?;
+
+                    $name::try_from(uuid).map_err(D::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+impl_versioned!(UuidV1, UuidV3, UuidV4, UuidV5, UuidV6, UuidV7, UuidV8);
+
 pub mod compact {
     //! Serialize a [`Uuid`] as a `[u8; 16]`.
     //!
@@ -360,6 +479,87 @@ pub mod compact {
     }
 }
 
+/// Serialize a [`Uuid`] as a `[u8; 16]` with the time-low and time-high fields swapped.
+///
+/// This is the binary encoding used by MySQL's `UUID_TO_BIN(uuid, 1)` function.
+/// See [`Uuid::to_mysql_swapped_bytes`] for more details.
+///
+/// [`Uuid`]: ../../struct.Uuid.html
+/// [`Uuid::to_mysql_swapped_bytes`]: ../../struct.Uuid.html#method.to_mysql_swapped_bytes
+///
+/// ## Examples
+///
+/// ```
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct StructA {
+///     #[serde(with = "uuid::serde::mysql_swapped")]
+///     id: uuid::Uuid,
+/// }
+/// ```
+pub mod mysql_swapped {
+    /// Serialize from a [`Uuid`] as a swapped `[u8; 16]`
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &crate::Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::Serializer,
+    {
+        serde_core::Serialize::serialize(&u.to_mysql_swapped_bytes(), serializer)
+    }
+
+    /// Deserialize a swapped `[u8; 16]` as a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<crate::Uuid, D::Error>
+    where
+        D: serde_core::Deserializer<'de>,
+    {
+        let bytes: [u8; 16] = serde_core::Deserialize::deserialize(deserializer)?;
+
+        Ok(crate::Uuid::from_mysql_swapped_bytes(bytes))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde_derive::*;
+        use serde_test::Configure;
+
+        #[test]
+        fn test_serialize_mysql_swapped() {
+            #[derive(Serialize, Debug, Deserialize, PartialEq, Clone)]
+            struct UuidContainer {
+                #[serde(with = "crate::serde::mysql_swapped")]
+                u: crate::Uuid,
+            }
+
+            // From the MySQL reference manual for `UUID_TO_BIN`
+            let container = UuidContainer {
+                u: crate::Uuid::parse_str("6ccd780c-baba-1026-9564-5b8c656024db").unwrap(),
+            };
+
+            let swapped = [
+                0x10, 0x26, 0xba, 0xba, 0x6c, 0xcd, 0x78, 0x0c, 0x95, 0x64, 0x5b, 0x8c, 0x65, 0x60,
+                0x24, 0xdb,
+            ];
+
+            let mut tokens = std::vec![
+                serde_test::Token::Struct {
+                    name: "UuidContainer",
+                    len: 1,
+                },
+                serde_test::Token::Str("u"),
+                serde_test::Token::Tuple { len: 16 },
+            ];
+            tokens.extend(swapped.iter().map(|b| serde_test::Token::U8(*b)));
+            tokens.push(serde_test::Token::TupleEnd);
+            tokens.push(serde_test::Token::StructEnd);
+
+            serde_test::assert_tokens(&container.clone().compact(), &tokens);
+            serde_test::assert_tokens(&container.readable(), &tokens);
+        }
+    }
+}
+
 /// Serialize a [`Uuid`] as [`uuid::fmt::Simple`].
 ///
 /// [`Uuid`]: ../../struct.Uuid.html
@@ -851,6 +1051,250 @@ pub mod urn {
     }
 }
 
+/// Serialize a [`Uuid`] as [`crate::fmt::Base32`].
+///
+/// [`Uuid`]: ../../struct.Uuid.html
+///
+/// ## Examples
+///
+/// Serialize and deserialize using the base32 format, failing to deserialize
+/// any other format:
+///
+/// ```
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct StructA {
+///     #[serde(with = "uuid::serde::base32")]
+///     id: uuid::Uuid,
+/// }
+/// ```
+///
+/// Serialize using the base32 format, but deserialize any format:
+///
+/// ```
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct StructB {
+///     #[serde(serialize_with = "uuid::serde::base32::serialize")]
+///     id: uuid::Uuid,
+/// }
+/// ```
+pub mod base32 {
+    use super::*;
+
+    /// Serialize a [`Uuid`] as a lower-case base32 string.
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[derive(serde_derive::Serialize)]
+    /// struct Struct {
+    ///     #[serde(serialize_with = "uuid::serde::base32::serialize")]
+    ///     id: uuid::Uuid,
+    /// }
+    ///
+    /// ```
+    pub fn serialize<S>(u: &crate::Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::Serializer,
+    {
+        serde_core::Serialize::serialize(u.as_base32(), serializer)
+    }
+
+    /// Deserialize a base32-formatted string as a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<crate::Uuid, D::Error>
+    where
+        D: serde_core::Deserializer<'de>,
+    {
+        Ok(Base32::deserialize(deserializer)?.into())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde::de::{self, Error};
+        use serde_test::{Readable, Token};
+
+        use super::*;
+
+        const HYPHENATED_UUID_STR: &str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+        const BASE32_UUID_STR: &str = "7s2t65xknj9ynbdfsjkfssz8f4";
+
+        #[test]
+        fn test_serialize_as_base32() {
+            #[derive(serde_derive::Serialize)]
+            struct Struct(#[serde(with = "super")] crate::Uuid);
+
+            let u = Struct(Uuid::parse_str(HYPHENATED_UUID_STR).unwrap());
+            serde_test::assert_ser_tokens(
+                &u,
+                &[
+                    Token::NewtypeStruct { name: "Struct" },
+                    Token::Str(BASE32_UUID_STR),
+                ],
+            );
+        }
+
+        #[test]
+        fn test_de_from_base32() {
+            #[derive(PartialEq, Debug, serde_derive::Deserialize)]
+            struct Struct(#[serde(with = "super")] crate::Uuid);
+            let s = Struct(HYPHENATED_UUID_STR.parse().unwrap());
+            serde_test::assert_de_tokens::<Struct>(
+                &s,
+                &[
+                    Token::TupleStruct {
+                        name: "Struct",
+                        len: 1,
+                    },
+                    Token::BorrowedStr("7S2T65XKNJ9YNBDFSJKFSSZ8F4"),
+                    Token::TupleStructEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn test_de_reject_hyphenated() {
+            #[derive(PartialEq, Debug, serde_derive::Deserialize)]
+            struct Struct(#[serde(with = "super")] crate::Uuid);
+            serde_test::assert_de_tokens_error::<Readable<Struct>>(
+                &[
+                    Token::TupleStruct {
+                        name: "Struct",
+                        len: 1,
+                    },
+                    Token::BorrowedStr(HYPHENATED_UUID_STR),
+                    Token::TupleStructEnd,
+                ],
+                &format!("{}", de::value::Error::custom("UUID parsing failed: invalid length: expected length 26 for base32 format, found 36")),
+            );
+        }
+    }
+}
+
+/// Serialize a [`Uuid`] as [`crate::fmt::Base64Url`].
+///
+/// [`Uuid`]: ../../struct.Uuid.html
+///
+/// ## Examples
+///
+/// Serialize and deserialize using the base64url format, failing to deserialize
+/// any other format:
+///
+/// ```
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct StructA {
+///     #[serde(with = "uuid::serde::base64url")]
+///     id: uuid::Uuid,
+/// }
+/// ```
+///
+/// Serialize using the base64url format, but deserialize any format:
+///
+/// ```
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct StructB {
+///     #[serde(serialize_with = "uuid::serde::base64url::serialize")]
+///     id: uuid::Uuid,
+/// }
+/// ```
+pub mod base64url {
+    use super::*;
+
+    /// Serialize a [`Uuid`] as a base64url string.
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[derive(serde_derive::Serialize)]
+    /// struct Struct {
+    ///     #[serde(serialize_with = "uuid::serde::base64url::serialize")]
+    ///     id: uuid::Uuid,
+    /// }
+    ///
+    /// ```
+    pub fn serialize<S>(u: &crate::Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::Serializer,
+    {
+        serde_core::Serialize::serialize(u.as_base64url(), serializer)
+    }
+
+    /// Deserialize a base64url-formatted string as a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<crate::Uuid, D::Error>
+    where
+        D: serde_core::Deserializer<'de>,
+    {
+        Ok(Base64Url::deserialize(deserializer)?.into())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde::de::{self, Error};
+        use serde_test::{Readable, Token};
+
+        use super::*;
+
+        const HYPHENATED_UUID_STR: &str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+        const BASE64URL_UUID_STR: &str = "-RaMXs6yT6q2vzKb85-h5A";
+
+        #[test]
+        fn test_serialize_as_base64url() {
+            #[derive(serde_derive::Serialize)]
+            struct Struct(#[serde(with = "super")] crate::Uuid);
+
+            let u = Struct(Uuid::parse_str(HYPHENATED_UUID_STR).unwrap());
+            serde_test::assert_ser_tokens(
+                &u,
+                &[
+                    Token::NewtypeStruct { name: "Struct" },
+                    Token::Str(BASE64URL_UUID_STR),
+                ],
+            );
+        }
+
+        #[test]
+        fn test_de_from_base64url() {
+            #[derive(PartialEq, Debug, serde_derive::Deserialize)]
+            struct Struct(#[serde(with = "super")] crate::Uuid);
+            let s = Struct(HYPHENATED_UUID_STR.parse().unwrap());
+            serde_test::assert_de_tokens::<Struct>(
+                &s,
+                &[
+                    Token::TupleStruct {
+                        name: "Struct",
+                        len: 1,
+                    },
+                    Token::BorrowedStr(BASE64URL_UUID_STR),
+                    Token::TupleStructEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn test_de_reject_hyphenated() {
+            #[derive(PartialEq, Debug, serde_derive::Deserialize)]
+            struct Struct(#[serde(with = "super")] crate::Uuid);
+            serde_test::assert_de_tokens_error::<Readable<Struct>>(
+                &[
+                    Token::TupleStruct {
+                        name: "Struct",
+                        len: 1,
+                    },
+                    Token::BorrowedStr(HYPHENATED_UUID_STR),
+                    Token::TupleStructEnd,
+                ],
+                &format!("{}", de::value::Error::custom("UUID parsing failed: invalid length: expected length 22 for base64url format, found 36")),
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod serde_tests {
     use super::*;
@@ -934,6 +1378,22 @@ mod serde_tests {
         serde_test::assert_de_tokens(&u.braced(), &[Token::Str(uuid_str)]);
     }
 
+    #[test]
+    fn test_serialize_base32() {
+        let uuid_str = "7s2t65xknj9ynbdfsjkfssz8f4";
+        let u = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+        serde_test::assert_ser_tokens(&u.base32(), &[Token::Str(uuid_str)]);
+        serde_test::assert_de_tokens(&u.base32(), &[Token::Str(uuid_str)]);
+    }
+
+    #[test]
+    fn test_serialize_base64url() {
+        let uuid_str = "-RaMXs6yT6q2vzKb85-h5A";
+        let u = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+        serde_test::assert_ser_tokens(&u.base64url(), &[Token::Str(uuid_str)]);
+        serde_test::assert_de_tokens(&u.base64url(), &[Token::Str(uuid_str)]);
+    }
+
     #[test]
     fn test_serialize_non_human_readable() {
         let uuid_bytes = b"F9168C5E-CEB2-4F";
@@ -959,6 +1419,57 @@ mod serde_tests {
         );
     }
 
+    #[test]
+    fn test_serde_versioned_uuid() {
+        let uuid_str = "017f22e2-79b0-7cc3-98c4-dc0c0c07398f";
+        let uuid_bytes = &[
+            0x01, 0x7f, 0x22, 0xe2, 0x79, 0xb0, 0x7c, 0xc3, 0x98, 0xc4, 0xdc, 0x0c, 0x0c, 0x07,
+            0x39, 0x8f,
+        ];
+        let v7 = UuidV7::try_from(Uuid::parse_str(uuid_str).unwrap()).unwrap();
+
+        serde_test::assert_tokens(&v7.readable(), &[Token::Str(uuid_str)]);
+        serde_test::assert_tokens(&v7.compact(), &[Token::Bytes(uuid_bytes)]);
+
+        serde_test::assert_de_tokens_error::<Readable<UuidV4>>(
+            &[Token::Str(uuid_str)],
+            "unexpected UUID version: expected 4, found 7",
+        );
+        serde_test::assert_de_tokens_error::<Compact<UuidV4>>(
+            &[Token::Bytes(uuid_bytes)],
+            "unexpected UUID version: expected 4, found 7",
+        );
+    }
+
+    #[test]
+    fn test_serde_typed_uuid() {
+        let uuid_str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+        let typed = TypedUuid::<str>::from_uuid(Uuid::parse_str(uuid_str).unwrap());
+
+        serde_test::assert_tokens(&typed.readable(), &[Token::Str(uuid_str)]);
+        serde_test::assert_tokens(
+            &typed.compact(),
+            &[Token::Bytes(&[
+                0xf9, 0x16, 0x8c, 0x5e, 0xce, 0xb2, 0x4f, 0xaa, 0xb6, 0xbf, 0x32, 0x9b, 0xf3, 0x9f,
+                0xa1, 0xe4,
+            ])],
+        );
+    }
+
+    #[test]
+    fn test_serde_type_id() {
+        let type_id_str = "user_01h455vb4pex5vsknk084sn02q";
+        let type_id = TypeId::parse_str(type_id_str).unwrap();
+
+        serde_test::assert_tokens(&type_id.readable(), &[Token::Str(type_id_str)]);
+        serde_test::assert_tokens(&type_id.compact(), &[Token::Str(type_id_str)]);
+
+        serde_test::assert_de_tokens_error::<Readable<TypeId>>(
+            &[Token::Str("user_8zzzzzzzzzzzzzzzzzzzzzzzzz")],
+            "UUID parsing failed: invalid base32: the first character must be between `0` and `7`",
+        );
+    }
+
     #[test]
     fn test_serde_non_nil_uuid() {
         let uuid_str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
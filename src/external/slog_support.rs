@@ -9,7 +9,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{non_nil::NonNilUuid, Uuid};
+use crate::{
+    non_nil::NonNilUuid, typed::TypedUuid, Uuid, UuidV1, UuidV3, UuidV4, UuidV5, UuidV6, UuidV7,
+    UuidV8,
+};
 
 impl slog::Value for Uuid {
     fn serialize(
@@ -33,6 +36,36 @@ impl slog::Value for NonNilUuid {
     }
 }
 
+impl<T: ?Sized> slog::Value for TypedUuid<T> {
+    fn serialize(
+        &self,
+        record: &slog::Record<'_>,
+        key: slog::Key,
+        serializer: &mut dyn slog::Serializer,
+    ) -> Result<(), slog::Error> {
+        self.untyped().serialize(record, key, serializer)
+    }
+}
+
+macro_rules! impl_versioned {
+    ($($name:ident),*) => {
+        $(
+            impl slog::Value for $name {
+                fn serialize(
+                    &self,
+                    record: &slog::Record<'_>,
+                    key: slog::Key,
+                    serializer: &mut dyn slog::Serializer,
+                ) -> Result<(), slog::Error> {
+                    Uuid::from(*self).serialize(record, key, serializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_versioned!(UuidV1, UuidV3, UuidV4, UuidV5, UuidV6, UuidV7, UuidV8);
+
 #[cfg(test)]
 mod tests {
     use crate::tests::some_uuid_v4;
@@ -45,4 +78,11 @@ mod tests {
         let u1 = some_uuid_v4();
         crit!(root, "test"; "u1" => u1);
     }
+
+    #[test]
+    fn test_slog_kv_versioned() {
+        let root = slog::Logger::root(slog::Discard.fuse(), slog::o!());
+        let u1 = crate::UuidV4::try_from(some_uuid_v4()).unwrap();
+        crit!(root, "test"; "u1" => u1);
+    }
 }
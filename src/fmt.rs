@@ -21,6 +21,119 @@ use crate::{
 #[cfg(feature = "std")]
 use crate::std::string::{String, ToString};
 
+macro_rules! impl_fmt_traits {
+    ($($T:ident<$($a:lifetime),*>),+) => {$(
+        impl_fmt_traits!($T<$($a),*> => LowerHex);
+    )+};
+    ($T:ident<$($a:lifetime),*> => $Display:ident) => {
+        impl<$($a),*> fmt::Display for $T<$($a),*> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$Display::fmt(self, f)
+            }
+        }
+
+        impl<$($a),*> fmt::LowerHex for $T<$($a),*> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.encode_lower(&mut [0; Self::LENGTH]))
+            }
+        }
+
+        impl<$($a),*> fmt::UpperHex for $T<$($a),*> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.encode_upper(&mut [0; Self::LENGTH]))
+            }
+        }
+
+        impl_fmt_from!($T<$($a),*>);
+    };
+}
+
+macro_rules! impl_fmt_from {
+    ($T:ident<>) => {
+        impl From<Uuid> for $T {
+            #[inline]
+            fn from(f: Uuid) -> Self {
+                $T(f)
+            }
+        }
+
+        impl From<$T> for Uuid {
+            #[inline]
+            fn from(f: $T) -> Self {
+                f.into_uuid()
+            }
+        }
+
+        impl AsRef<Uuid> for $T {
+            #[inline]
+            fn as_ref(&self) -> &Uuid {
+                &self.0
+            }
+        }
+
+        impl Borrow<Uuid> for $T {
+            #[inline]
+            fn borrow(&self) -> &Uuid {
+                &self.0
+            }
+        }
+    };
+    ($T:ident<$a:lifetime>) => {
+        impl<$a> From<&$a Uuid> for $T<$a> {
+            #[inline]
+            fn from(f: &$a Uuid) -> Self {
+                $T::from_uuid_ref(f)
+            }
+        }
+
+        impl<$a> From<$T<$a>> for &$a Uuid {
+            #[inline]
+            fn from(f: $T<$a>) -> &$a Uuid {
+                f.0
+            }
+        }
+
+        impl<$a> AsRef<Uuid> for $T<$a> {
+            #[inline]
+            fn as_ref(&self) -> &Uuid {
+                self.0
+            }
+        }
+
+        impl<$a> Borrow<Uuid> for $T<$a> {
+            #[inline]
+            fn borrow(&self) -> &Uuid {
+                self.0
+            }
+        }
+    };
+}
+
+mod base32;
+mod base32check;
+mod base64url;
+mod custom;
+mod decimal;
+mod oid;
+mod proquint;
+mod radix;
+mod source;
+mod typeid;
+
+pub use self::{
+    base32::Base32,
+    base32check::Base32Check,
+    base64url::Base64Url,
+    custom::{Custom, Spec},
+    decimal::Decimal,
+    oid::Oid,
+    proquint::Proquint,
+    radix::{Alphabet, Radix},
+    source::{CSharp, CStruct, RustLiteral},
+    typeid::TypeId,
+};
+
 impl std::fmt::Debug for Uuid {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -908,92 +1021,6 @@ impl FromStr for Braced {
     }
 }
 
-macro_rules! impl_fmt_traits {
-    ($($T:ident<$($a:lifetime),*>),+) => {$(
-        impl<$($a),*> fmt::Display for $T<$($a),*> {
-            #[inline]
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt::LowerHex::fmt(self, f)
-            }
-        }
-
-        impl<$($a),*> fmt::LowerHex for $T<$($a),*> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(self.encode_lower(&mut [0; Self::LENGTH]))
-            }
-        }
-
-        impl<$($a),*> fmt::UpperHex for $T<$($a),*> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(self.encode_upper(&mut [0; Self::LENGTH]))
-            }
-        }
-
-        impl_fmt_from!($T<$($a),*>);
-    )+}
-}
-
-macro_rules! impl_fmt_from {
-    ($T:ident<>) => {
-        impl From<Uuid> for $T {
-            #[inline]
-            fn from(f: Uuid) -> Self {
-                $T(f)
-            }
-        }
-
-        impl From<$T> for Uuid {
-            #[inline]
-            fn from(f: $T) -> Self {
-                f.into_uuid()
-            }
-        }
-
-        impl AsRef<Uuid> for $T {
-            #[inline]
-            fn as_ref(&self) -> &Uuid {
-                &self.0
-            }
-        }
-
-        impl Borrow<Uuid> for $T {
-            #[inline]
-            fn borrow(&self) -> &Uuid {
-                &self.0
-            }
-        }
-    };
-    ($T:ident<$a:lifetime>) => {
-        impl<$a> From<&$a Uuid> for $T<$a> {
-            #[inline]
-            fn from(f: &$a Uuid) -> Self {
-                $T::from_uuid_ref(f)
-            }
-        }
-
-        impl<$a> From<$T<$a>> for &$a Uuid {
-            #[inline]
-            fn from(f: $T<$a>) -> &$a Uuid {
-                f.0
-            }
-        }
-
-        impl<$a> AsRef<Uuid> for $T<$a> {
-            #[inline]
-            fn as_ref(&self) -> &Uuid {
-                self.0
-            }
-        }
-
-        impl<$a> Borrow<Uuid> for $T<$a> {
-            #[inline]
-            fn borrow(&self) -> &Uuid {
-                self.0
-            }
-        }
-    };
-}
-
 impl_fmt_traits! {
     Hyphenated<>,
     Simple<>,
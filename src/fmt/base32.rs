@@ -0,0 +1,435 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::str::FromStr;
+
+use crate::{
+    error::*,
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+// The Crockford base32 alphabet
+pub(super) const UPPER: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+pub(super) const LOWER: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+// Maps an ASCII byte back to its 5-bit value, or `0xff` if it's not in the alphabet.
+// Decoding is case-insensitive, and the ambiguous characters `I`, `L`, and `O` are
+// read as the digits they look like
+pub(super) const DECODE: [u8; 256] = {
+    let mut table = [0xff; 256];
+    let mut i = 0;
+
+    while i < UPPER.len() {
+        table[UPPER[i] as usize] = i as u8;
+        table[LOWER[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    table[b'I' as usize] = 1;
+    table[b'i' as usize] = 1;
+    table[b'L' as usize] = 1;
+    table[b'l' as usize] = 1;
+    table[b'O' as usize] = 0;
+    table[b'o' as usize] = 0;
+
+    table
+};
+
+/// Format a [`Uuid`] as a Crockford base32 string, like
+/// `01h455vb4pex5vsknk084sn02q`.
+///
+/// The UUID is encoded as a 128-bit big-endian number using the 32 digits and
+/// letters of Crockford's base32 alphabet, which leaves out `I`, `L`, `O`, and `U`.
+/// The result is always 26 characters long. This is the same encoding used by ULIDs
+/// and TypeID suffixes.
+///
+/// Decoding is case-insensitive, and `I` and `L` are read as `1`, and `O` as `0`,
+/// so codes that have been typed in by hand can still be parsed. Hyphens and check
+/// symbols aren't supported.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::Base32, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057")?;
+///
+/// assert_eq!("01h455vb4pex5vsknk084sn02q", uuid.base32().to_string());
+/// assert_eq!("01H455VB4PEX5VSKNK084SN02Q", format!("{:X}", uuid.base32()));
+///
+/// let parsed: Base32 = "01H455VB4PEX5VSKNK084SNO2Q".parse()?;
+///
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///
+/// * [Crockford's Base32](https://www.crockford.com/base32.html)
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    all(uuid_unstable, feature = "zerocopy"),
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct Base32(Uuid);
+
+impl Uuid {
+    /// Get a [`Base32`] formatter.
+    #[inline]
+    pub const fn base32(self) -> Base32 {
+        Base32(self)
+    }
+
+    /// Get a borrowed [`Base32`] formatter.
+    #[inline]
+    pub fn as_base32(&self) -> &Base32 {
+        unsafe_transmute_ref!(self)
+    }
+}
+
+impl Base32 {
+    /// The length of a base32 [`Uuid`] string.
+    pub const LENGTH: usize = 26;
+
+    /// Creates a [`Base32`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Base32(uuid)
+    }
+
+    /// Parses a Crockford base32 string.
+    ///
+    /// The input must be exactly 26 characters. 26 characters can hold 130 bits,
+    /// so the first character must be between `0` and `7` for the value to fit
+    /// in a UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Base32, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// assert_eq!(Uuid::max(), Base32::parse_str("7zzzzzzzzzzzzzzzzzzzzzzzzz")?.into_uuid());
+    ///
+    /// assert!(Base32::parse_str("8zzzzzzzzzzzzzzzzzzzzzzzzz").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(Base32(uuid)),
+            Err(Invalid::Length) => Err(Error(ErrorKind::Base32Length { len: input.len() })),
+            Err(Invalid::Char(index)) => Err(Error(ErrorKind::ParseChar {
+                // Every byte before the index is ASCII, so it's at a character boundary
+                character: input[index..].chars().next().unwrap(),
+                index: index + 1,
+            })),
+            Err(Invalid::Overflow) => Err(Error(ErrorKind::Base32Overflow)),
+        }
+    }
+
+    /// Parses a Crockford base32 string in a `const` context.
+    ///
+    /// This method accepts the same input as [`Base32::parse_str`], but
+    /// won't generate very useful error messages if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Base32, Uuid};
+    /// const ID: Base32 = match Base32::try_parse("01h455vb4pex5vsknk084sn02q") {
+    ///     Ok(id) => id,
+    ///     Err(_) => panic!("invalid base32"),
+    /// };
+    ///
+    /// assert_eq!("01890a5d-ac96-774b-bcce-b302099a8057", ID.as_uuid().to_string());
+    /// ```
+    pub const fn try_parse(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(Base32(uuid)),
+            Err(_) => Err(Error(ErrorKind::ParseOther)),
+        }
+    }
+
+    /// Writes the [`Uuid`] as a lower-case base32 string to `buffer`,
+    /// and returns the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// // the buffer is mutated directly, and trailing contents remains
+    /// let mut buf = [b'!'; 28];
+    /// assert_eq!("37wn84845h89qs4hxvd075zr68", uuid.base32().encode_lower(&mut buf));
+    /// assert_eq!(b"37wn84845h89qs4hxvd075zr68!!", &buf);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base32(&self.0, buffer, false)
+    }
+
+    /// Writes the [`Uuid`] as an upper-case base32 string to `buffer`,
+    /// and returns the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(
+    ///     "37WN84845H89QS4HXVD075ZR68",
+    ///     uuid.base32().encode_upper(&mut Uuid::encode_buffer()),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base32(&self.0, buffer, true)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Base32`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+pub(super) const fn format_base32(uuid: &Uuid, upper: bool) -> [u8; Base32::LENGTH] {
+    let alphabet = if upper { UPPER } else { LOWER };
+    let value = uuid.as_u128();
+    let mut dst = [0; Base32::LENGTH];
+
+    // Each character encodes 5 bits, starting from the least significant
+    let mut i = 0;
+    while i < Base32::LENGTH {
+        dst[Base32::LENGTH - 1 - i] = alphabet[((value >> (i * 5)) & 0x1f) as usize];
+        i += 1;
+    }
+
+    dst
+}
+
+#[inline]
+fn encode_base32<'b>(uuid: &Uuid, buffer: &'b mut [u8], upper: bool) -> &'b mut str {
+    let buf = &mut buffer[..Base32::LENGTH];
+    let buf: &mut [u8; Base32::LENGTH] = buf.try_into().unwrap();
+    *buf = format_base32(uuid, upper);
+
+    // SAFETY: The encoded buffer is ASCII encoded
+    unsafe { str::from_utf8_unchecked_mut(buf) }
+}
+
+pub(super) enum Invalid {
+    Length,
+    Char(usize),
+    Overflow,
+}
+
+pub(super) const fn decode(input: &[u8]) -> Result<Uuid, Invalid> {
+    if input.len() != Base32::LENGTH {
+        return Err(Invalid::Length);
+    }
+
+    let mut value = 0u128;
+
+    let mut i = 0;
+    while i < Base32::LENGTH {
+        let digit = DECODE[input[i] as usize];
+
+        if digit == 0xff {
+            return Err(Invalid::Char(i));
+        }
+
+        value = (value << 5) | digit as u128;
+        i += 1;
+    }
+
+    // The first character can only hold 3 bits,
+    // since the string encodes 130 bits
+    if DECODE[input[0] as usize] > 7 {
+        return Err(Invalid::Overflow);
+    }
+
+    Ok(Uuid::from_u128(value))
+}
+
+impl FromStr for Base32 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Base32::parse_str(s)
+    }
+}
+
+impl_fmt_traits! {
+    Base32<>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    const VALID: &[(&str, &str)] = &[
+        (
+            "00000000-0000-0000-0000-000000000000",
+            "00000000000000000000000000",
+        ),
+        (
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "7zzzzzzzzzzzzzzzzzzzzzzzzz",
+        ),
+        (
+            "01890a5d-ac96-774b-bcce-b302099a8057",
+            "01h455vb4pex5vsknk084sn02q",
+        ),
+        (
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "37wn84845h89qs4hxvd075zr68",
+        ),
+        (
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            "7s2t65xknj9ynbdfsjkfssz8f4",
+        ),
+    ];
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_roundtrip() {
+        for (uuid, encoded) in VALID {
+            let uuid = Uuid::parse_str(uuid).unwrap();
+            let upper = encoded.to_ascii_uppercase();
+
+            assert_eq!(*encoded, uuid.base32().to_string());
+            assert_eq!(*encoded, uuid.as_base32().to_string());
+            assert_eq!(upper, format!("{:X}", uuid.base32()));
+
+            assert_eq!(uuid, Base32::parse_str(encoded).unwrap().into_uuid());
+            assert_eq!(uuid, Base32::parse_str(&upper).unwrap().into_uuid());
+            assert_eq!(uuid, Base32::try_parse(encoded).unwrap().into_uuid());
+            assert_eq!(uuid, encoded.parse::<Base32>().unwrap().into_uuid());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_ambiguous_characters() {
+        let expected = Base32::parse_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+        for ambiguous in [
+            "oIh455vb4pex5vsknk084sn02q",
+            "OLh455vb4pex5vsknk084sn02q",
+            "0ih455vb4pex5vsknk084sno2q",
+            "0lH455VB4PEX5VSKNK084SNO2Q",
+        ] {
+            assert_eq!(expected, Base32::parse_str(ambiguous).unwrap());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_invalid() {
+        assert_eq!(
+            "invalid length: expected length 26 for base32 format, found 25",
+            Base32::parse_str("01h455vb4pex5vsknk084sn02")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "invalid length: expected length 26 for base32 format, found 36",
+            Base32::parse_str("01890a5d-ac96-774b-bcce-b302099a8057")
+                .unwrap_err()
+                .to_string()
+        );
+
+        // `U` isn't part of the alphabet
+        assert_eq!(
+            "invalid character: found `u` at 3",
+            Base32::parse_str("01u455vb4pex5vsknk084sn02q")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "invalid character: found `é` at 3",
+            Base32::parse_str("01é455vb4pex5vsknk084sn0q")
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert_eq!(
+            "invalid base32: the first character must be between `0` and `7`",
+            Base32::parse_str("8zzzzzzzzzzzzzzzzzzzzzzzzz")
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert!(Base32::try_parse("8zzzzzzzzzzzzzzzzzzzzzzzzz").is_err());
+        assert!(Base32::try_parse("").is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_small_buffer() {
+        Uuid::nil()
+            .base32()
+            .encode_lower(&mut [0; Base32::LENGTH - 1]);
+    }
+}
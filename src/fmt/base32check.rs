@@ -0,0 +1,577 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::str::FromStr;
+
+use super::base32::{DECODE, LOWER, UPPER};
+use crate::{
+    error::*,
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+// Crockford's check symbols extend the base32 alphabet up to 37 values
+const CHECK_UPPER: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+const CHECK_LOWER: &[u8; 37] = b"0123456789abcdefghjkmnpqrstvwxyz*~$=u";
+
+// Maps an ASCII byte back to its check value, or `0xff` if it's not a check symbol
+const DECODE_CHECK: [u8; 256] = {
+    let mut table = DECODE;
+
+    table[b'*' as usize] = 32;
+    table[b'~' as usize] = 33;
+    table[b'$' as usize] = 34;
+    table[b'=' as usize] = 35;
+    table[b'U' as usize] = 36;
+    table[b'u' as usize] = 36;
+
+    table
+};
+
+// The number of base32 symbols that encode the UUID, not including the check symbol
+const DATA_SYMBOLS: usize = 26;
+// The number of symbols in each hyphenated group
+const GROUP: usize = 3;
+
+/// Format a [`Uuid`] as a Crockford base32 string with a check symbol, like
+/// `01H-455-VB4-PEX-5VS-KNK-084-SN0-2QA`.
+///
+/// The UUID is encoded as [`Base32`](super::Base32), followed by a check symbol that's
+/// the UUID modulo 37. The 27 symbols are split into groups of 3 by hyphens, so the
+/// result is always 35 characters long. This format is meant for UUIDs that people
+/// read and type by hand: the check symbol catches any single mistyped character, and
+/// any two adjacent characters that have been swapped.
+///
+/// Decoding is case-insensitive, and `I` and `L` are read as `1`, and `O` as `0`.
+/// Hyphens are ignored wherever they appear. If the string is well-formed but the check
+/// symbol doesn't match, [`Error::is_checksum_mismatch`] will return `true`.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::Base32Check, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057")?;
+///
+/// assert_eq!("01H-455-VB4-PEX-5VS-KNK-084-SN0-2QA", uuid.base32_check().to_string());
+///
+/// let parsed: Base32Check = "01h455vb4pex5vsknk084sn02qa".parse()?;
+///
+/// assert_eq!(uuid, parsed.into_uuid());
+///
+/// // The `V` and `B` have been swapped
+/// let err = "01H-455-BV4-PEX-5VS-KNK-084-SN0-2QA".parse::<Base32Check>().unwrap_err();
+///
+/// assert!(err.is_checksum_mismatch());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///
+/// * [Crockford's Base32](https://www.crockford.com/base32.html)
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    all(uuid_unstable, feature = "zerocopy"),
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct Base32Check(Uuid);
+
+impl Uuid {
+    /// Get a [`Base32Check`] formatter.
+    #[inline]
+    pub const fn base32_check(self) -> Base32Check {
+        Base32Check(self)
+    }
+
+    /// Get a borrowed [`Base32Check`] formatter.
+    #[inline]
+    pub fn as_base32_check(&self) -> &Base32Check {
+        unsafe_transmute_ref!(self)
+    }
+}
+
+impl Base32Check {
+    /// The length of a hyphenated base32 [`Uuid`] string with a check symbol.
+    pub const LENGTH: usize = 35;
+
+    /// Creates a [`Base32Check`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Base32Check(uuid)
+    }
+
+    /// Parses a Crockford base32 string with a check symbol.
+    ///
+    /// The input must contain exactly 27 symbols, not counting hyphens. The first 26
+    /// encode the UUID, and the first character must be between `0` and `7` for the value
+    /// to fit. The last is the check symbol, which can also be one of `*`, `~`, `$`, `=`,
+    /// or `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Base32Check, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// assert_eq!(
+    ///     Uuid::max(),
+    ///     Base32Check::parse_str("7ZZZ-ZZZZ-ZZZZ-ZZZZ-ZZZZ-ZZZZ-ZZ*")?.into_uuid(),
+    /// );
+    ///
+    /// // The string is malformed
+    /// let err = Base32Check::parse_str("7ZZZ-ZZZZ-ZZZZ-ZZZZ-ZZZZ-ZZZZ-Z*").unwrap_err();
+    /// assert!(!err.is_checksum_mismatch());
+    ///
+    /// // The check symbol doesn't match
+    /// let err = Base32Check::parse_str("7ZZZ-ZZZZ-ZZZZ-ZZZZ-ZZZZ-ZZZZ-ZZ~").unwrap_err();
+    /// assert!(err.is_checksum_mismatch());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(Base32Check(uuid)),
+            Err(Invalid::Length(len)) => Err(Error(ErrorKind::Base32CheckLength { len })),
+            Err(Invalid::Char(index)) => Err(Error(ErrorKind::ParseChar {
+                // Every byte before the index is ASCII, so it's at a character boundary
+                character: input[index..].chars().next().unwrap(),
+                index: index + 1,
+            })),
+            Err(Invalid::Overflow) => Err(Error(ErrorKind::Base32Overflow)),
+            Err(Invalid::Mismatch { expected, index }) => {
+                Err(Error(ErrorKind::Base32CheckMismatch {
+                    expected: CHECK_UPPER[expected as usize] as char,
+                    // The check symbol is ASCII
+                    found: input.as_bytes()[index].to_ascii_uppercase() as char,
+                }))
+            }
+        }
+    }
+
+    /// Parses a Crockford base32 string with a check symbol in a `const` context.
+    ///
+    /// This method accepts the same input as [`Base32Check::parse_str`], but
+    /// won't generate very useful error messages if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Base32Check, Uuid};
+    /// const ID: Base32Check = match Base32Check::try_parse("01H-455-VB4-PEX-5VS-KNK-084-SN0-2QA") {
+    ///     Ok(id) => id,
+    ///     Err(_) => panic!("invalid code"),
+    /// };
+    ///
+    /// assert_eq!("01890a5d-ac96-774b-bcce-b302099a8057", ID.as_uuid().to_string());
+    /// ```
+    pub const fn try_parse(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(Base32Check(uuid)),
+            Err(_) => Err(Error(ErrorKind::ParseOther)),
+        }
+    }
+
+    /// Writes the [`Uuid`] as a lower-case base32 string with a check symbol
+    /// to `buffer`, and returns the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(
+    ///     "37w-n84-845-h89-qs4-hxv-d07-5zr-681",
+    ///     uuid.base32_check().encode_lower(&mut Uuid::encode_buffer()),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base32_check(&self.0, buffer, false)
+    }
+
+    /// Writes the [`Uuid`] as an upper-case base32 string with a check symbol
+    /// to `buffer`, and returns the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(
+    ///     "37W-N84-845-H89-QS4-HXV-D07-5ZR-681",
+    ///     uuid.base32_check().encode_upper(&mut Uuid::encode_buffer()),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base32_check(&self.0, buffer, true)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Base32Check`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+const fn format_base32_check(uuid: &Uuid, upper: bool) -> [u8; Base32Check::LENGTH] {
+    let (alphabet, check) = if upper {
+        (UPPER, CHECK_UPPER)
+    } else {
+        (LOWER, CHECK_LOWER)
+    };
+
+    let value = uuid.as_u128();
+    let mut dst = [b'-'; Base32Check::LENGTH];
+
+    // Each symbol encodes 5 bits, starting from the most significant,
+    // and is followed by a hyphen at the end of each group
+    let mut i = 0;
+    while i < DATA_SYMBOLS {
+        let shift = (DATA_SYMBOLS - 1 - i) * 5;
+
+        dst[i + i / GROUP] = alphabet[((value >> shift) & 0x1f) as usize];
+        i += 1;
+    }
+
+    dst[Base32Check::LENGTH - 1] = check[(value % 37) as usize];
+
+    dst
+}
+
+#[inline]
+fn encode_base32_check<'b>(uuid: &Uuid, buffer: &'b mut [u8], upper: bool) -> &'b mut str {
+    let buf = &mut buffer[..Base32Check::LENGTH];
+    let buf: &mut [u8; Base32Check::LENGTH] = buf.try_into().unwrap();
+    *buf = format_base32_check(uuid, upper);
+
+    // SAFETY: The encoded buffer is ASCII encoded
+    unsafe { str::from_utf8_unchecked_mut(buf) }
+}
+
+enum Invalid {
+    Length(usize),
+    Char(usize),
+    Overflow,
+    Mismatch { expected: u8, index: usize },
+}
+
+const fn decode(input: &[u8]) -> Result<Uuid, Invalid> {
+    // Hyphens are ignored wherever they appear, so count the other symbols first
+    let mut len = 0;
+
+    let mut i = 0;
+    while i < input.len() {
+        if input[i] != b'-' {
+            len += 1;
+        }
+
+        i += 1;
+    }
+
+    if len != DATA_SYMBOLS + 1 {
+        return Err(Invalid::Length(len));
+    }
+
+    let mut value = 0u128;
+    let mut first = 0;
+    let mut symbols = 0;
+
+    let mut i = 0;
+    while i < input.len() {
+        if input[i] == b'-' {
+            i += 1;
+            continue;
+        }
+
+        if symbols < DATA_SYMBOLS {
+            let digit = DECODE[input[i] as usize];
+
+            if digit == 0xff {
+                return Err(Invalid::Char(i));
+            }
+
+            if symbols == 0 {
+                first = digit;
+            }
+
+            value = (value << 5) | digit as u128;
+        } else {
+            let check = DECODE_CHECK[input[i] as usize];
+
+            if check == 0xff {
+                return Err(Invalid::Char(i));
+            }
+
+            // The first symbol can only hold 3 bits,
+            // since the string encodes 130 bits
+            if first > 7 {
+                return Err(Invalid::Overflow);
+            }
+
+            let expected = (value % 37) as u8;
+
+            if check != expected {
+                return Err(Invalid::Mismatch { expected, index: i });
+            }
+        }
+
+        symbols += 1;
+        i += 1;
+    }
+
+    Ok(Uuid::from_u128(value))
+}
+
+impl FromStr for Base32Check {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Base32Check::parse_str(s)
+    }
+}
+
+impl_fmt_traits! {
+    Base32Check<> => UpperHex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    const VALID: &[(&str, &str)] = &[
+        (
+            "00000000-0000-0000-0000-000000000000",
+            "000-000-000-000-000-000-000-000-000",
+        ),
+        (
+            "00000000-0000-0000-0000-000000000021",
+            "000-000-000-000-000-000-000-000-11~",
+        ),
+        (
+            "00000000-0000-0000-0000-000000000022",
+            "000-000-000-000-000-000-000-000-12$",
+        ),
+        (
+            "00000000-0000-0000-0000-000000000024",
+            "000-000-000-000-000-000-000-000-14U",
+        ),
+        (
+            "01890a5d-ac96-774b-bcce-b302099a8057",
+            "01H-455-VB4-PEX-5VS-KNK-084-SN0-2QA",
+        ),
+        (
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "37W-N84-845-H89-QS4-HXV-D07-5ZR-681",
+        ),
+        (
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "7ZZ-ZZZ-ZZZ-ZZZ-ZZZ-ZZZ-ZZZ-ZZZ-ZZ*",
+        ),
+    ];
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_roundtrip() {
+        for (uuid, encoded) in VALID {
+            let uuid = Uuid::parse_str(uuid).unwrap();
+            let lower = encoded.to_ascii_lowercase();
+            let unhyphenated = encoded.replace('-', "");
+
+            assert_eq!(*encoded, uuid.base32_check().to_string());
+            assert_eq!(*encoded, uuid.as_base32_check().to_string());
+            assert_eq!(lower, format!("{:x}", uuid.base32_check()));
+
+            for input in [*encoded, &lower, &unhyphenated] {
+                assert_eq!(uuid, Base32Check::parse_str(input).unwrap().into_uuid());
+                assert_eq!(uuid, Base32Check::try_parse(input).unwrap().into_uuid());
+                assert_eq!(uuid, input.parse::<Base32Check>().unwrap().into_uuid());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_ambiguous_characters() {
+        let expected = Base32Check::parse_str("01H-455-VB4-PEX-5VS-KNK-084-SN0-2QA").unwrap();
+
+        for ambiguous in [
+            "OIH-455-VB4-PEX-5VS-KNK-084-SNO-2QA",
+            "oLh-455-vb4-pex-5vs-knk-084-sno-2qa",
+            "-01-H45-5VB-4PE-X5V-SKN-K08-4SN-02Q-A-",
+        ] {
+            assert_eq!(expected, Base32Check::parse_str(ambiguous).unwrap());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_detects_typos() {
+        let code = "37W-N84-845-H89-QS4-HXV-D07-5ZR-681".as_bytes();
+
+        for i in (0..code.len()).filter(|i| code[*i] != b'-') {
+            // Every single substitution is detected
+            for &symbol in UPPER {
+                let mut typo = code.to_vec();
+                typo[i] = symbol;
+
+                if typo == code {
+                    continue;
+                }
+
+                let err = Base32Check::parse_str(str::from_utf8(&typo).unwrap()).unwrap_err();
+
+                // Unless the first symbol overflows
+                assert!(
+                    err.is_checksum_mismatch() || (i == 0 && symbol > b'7'),
+                    "{err}"
+                );
+            }
+
+            // Every transposition of adjacent symbols is detected
+            let next = (i + 1..code.len()).find(|j| code[*j] != b'-');
+
+            if let Some(j) = next {
+                if code[i] != code[j] {
+                    let mut typo = code.to_vec();
+                    typo.swap(i, j);
+
+                    let err = Base32Check::parse_str(str::from_utf8(&typo).unwrap()).unwrap_err();
+
+                    assert!(err.is_checksum_mismatch(), "{err}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_invalid() {
+        for (input, err) in [
+            ("", ErrorKind::Base32CheckLength { len: 0 }),
+            ("---", ErrorKind::Base32CheckLength { len: 0 }),
+            (
+                "01H-455-VB4-PEX-5VS-KNK-084-SN0-2Q",
+                ErrorKind::Base32CheckLength { len: 26 },
+            ),
+            (
+                "01H-455-VB4-PEX-5VS-KNK-084-SN0-2QAA",
+                ErrorKind::Base32CheckLength { len: 28 },
+            ),
+            (
+                "01H 455-VB4-PEX-5VS-KNK-084-SN0-2Q",
+                ErrorKind::ParseChar {
+                    character: ' ',
+                    index: 4,
+                },
+            ),
+            // Check symbols can't be used for data
+            (
+                "01H-455-VB4-PEX-5VS-KNK-084-SN0-*QA",
+                ErrorKind::ParseChar {
+                    character: '*',
+                    index: 33,
+                },
+            ),
+            (
+                "01H-455-VB4-PEX-5VS-KNK-084-SN0-2Q!",
+                ErrorKind::ParseChar {
+                    character: '!',
+                    index: 35,
+                },
+            ),
+            (
+                "81H-455-VB4-PEX-5VS-KNK-084-SN0-2QA",
+                ErrorKind::Base32Overflow,
+            ),
+        ] {
+            let parsed = Base32Check::parse_str(input).unwrap_err();
+
+            assert!(!parsed.is_checksum_mismatch(), "{input}");
+            assert_eq!(Error(err), parsed, "{input}");
+        }
+
+        let err = Base32Check::parse_str("01H-455-VB4-PEX-5VS-KNK-084-SN0-2Qb").unwrap_err();
+
+        assert!(err.is_checksum_mismatch());
+        assert_eq!(
+            Error(ErrorKind::Base32CheckMismatch {
+                expected: 'A',
+                found: 'B'
+            }),
+            err
+        );
+
+        assert_eq!(
+            Error(ErrorKind::ParseOther),
+            Base32Check::try_parse("01H-455-VB4-PEX-5VS-KNK-084-SN0-2QB").unwrap_err()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_small_buffer() {
+        Uuid::nil().base32_check().encode_upper(&mut [0; 34]);
+    }
+}
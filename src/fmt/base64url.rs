@@ -0,0 +1,392 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::str::FromStr;
+
+use crate::{
+    error::*,
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+// The URL and filename safe base64 alphabet
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// Maps an ASCII byte back to its 6-bit value, or `0xff` if it's not in the alphabet
+const DECODE: [u8; 256] = {
+    let mut table = [0xff; 256];
+    let mut i = 0;
+
+    while i < ALPHABET.len() {
+        table[ALPHABET[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    table
+};
+
+/// Format a [`Uuid`] as an unpadded base64url string, like
+/// `Z-VQRBCxQm-SR7toDl_gyA`.
+///
+/// The bytes of the UUID are encoded in big-endian order using the URL and
+/// filename safe alphabet from RFC 4648, without any trailing `=` padding.
+/// The result is always 22 characters long, and is safe to use in URLs and
+/// file names without escaping.
+///
+/// Base64url is case-sensitive, so there's no upper or lower-case variant
+/// of this format.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::Base64Url, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+///
+/// assert_eq!("Z-VQRBCxQm-SR7toDl_gyA", uuid.base64url().to_string());
+///
+/// let parsed: Base64Url = "Z-VQRBCxQm-SR7toDl_gyA".parse()?;
+///
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///
+/// * [Base 64 Encoding with URL and Filename Safe Alphabet in RFC 4648](https://www.rfc-editor.org/rfc/rfc4648#section-5)
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    all(uuid_unstable, feature = "zerocopy"),
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct Base64Url(Uuid);
+
+impl Uuid {
+    /// Get a [`Base64Url`] formatter.
+    #[inline]
+    pub const fn base64url(self) -> Base64Url {
+        Base64Url(self)
+    }
+
+    /// Get a borrowed [`Base64Url`] formatter.
+    #[inline]
+    pub fn as_base64url(&self) -> &Base64Url {
+        unsafe_transmute_ref!(self)
+    }
+}
+
+impl Base64Url {
+    /// The length of a base64url [`Uuid`] string.
+    pub const LENGTH: usize = 22;
+
+    /// Creates a [`Base64Url`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Base64Url(uuid)
+    }
+
+    /// Parses a base64url string.
+    ///
+    /// The input must be exactly 22 characters, without padding. The last
+    /// character only carries 2 bits of the UUID, so the 4 bits it has left
+    /// over must be zero. This means each UUID has exactly one base64url form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Base64Url, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let parsed = Base64Url::parse_str("k22gH5q9TZ2AxwKvhcgiqA")?;
+    ///
+    /// assert_eq!(Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?, parsed.into_uuid());
+    ///
+    /// assert!(Base64Url::parse_str("k22gH5q9TZ2AxwKvhcgiqA==").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(Base64Url(uuid)),
+            Err(Invalid::Length) => Err(Error(ErrorKind::Base64UrlLength { len: input.len() })),
+            Err(Invalid::Char(index)) => Err(Error(ErrorKind::ParseChar {
+                // Every byte before the index is ASCII, so it's at a character boundary
+                character: input[index..].chars().next().unwrap(),
+                index: index + 1,
+            })),
+        }
+    }
+
+    /// Parses a base64url string in a `const` context.
+    ///
+    /// This method accepts the same input as [`Base64Url::parse_str`], but
+    /// won't generate very useful error messages if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Base64Url, Uuid};
+    /// const ID: Base64Url = match Base64Url::try_parse("Z-VQRBCxQm-SR7toDl_gyA") {
+    ///     Ok(id) => id,
+    ///     Err(_) => panic!("invalid base64url"),
+    /// };
+    ///
+    /// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", ID.as_uuid().to_string());
+    /// ```
+    pub const fn try_parse(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(Base64Url(uuid)),
+            Err(_) => Err(Error(ErrorKind::ParseOther)),
+        }
+    }
+
+    /// Writes the [`Uuid`] as a base64url string to `buffer`, and returns
+    /// the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")?;
+    ///
+    /// // the buffer is mutated directly, and trailing contents remains
+    /// let mut buf = [b'!'; 24];
+    /// assert_eq!("-RaMXs6yT6q2vzKb85-h5A", uuid.base64url().encode(&mut buf));
+    /// assert_eq!(b"-RaMXs6yT6q2vzKb85-h5A!!", &buf);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let buf = &mut buffer[..Self::LENGTH];
+        let buf: &mut [u8; Self::LENGTH] = buf.try_into().unwrap();
+        *buf = format_base64url(&self.0);
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Base64Url`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+const fn format_base64url(uuid: &Uuid) -> [u8; Base64Url::LENGTH] {
+    let value = uuid.as_u128();
+    let mut dst = [0; Base64Url::LENGTH];
+
+    // The first 21 characters each encode 6 bits
+    let mut i = 0;
+    while i < Base64Url::LENGTH - 1 {
+        dst[i] = ALPHABET[((value >> (122 - i * 6)) & 0x3f) as usize];
+        i += 1;
+    }
+
+    // The last character encodes the remaining 2 bits, padded with zeros
+    dst[Base64Url::LENGTH - 1] = ALPHABET[((value & 0x03) << 4) as usize];
+
+    dst
+}
+
+enum Invalid {
+    Length,
+    Char(usize),
+}
+
+const fn decode(input: &[u8]) -> Result<Uuid, Invalid> {
+    if input.len() != Base64Url::LENGTH {
+        return Err(Invalid::Length);
+    }
+
+    let mut value = 0u128;
+
+    let mut i = 0;
+    while i < Base64Url::LENGTH {
+        let digit = DECODE[input[i] as usize];
+
+        if digit == 0xff {
+            return Err(Invalid::Char(i));
+        }
+
+        if i < Base64Url::LENGTH - 1 {
+            value = (value << 6) | digit as u128;
+        } else {
+            // The unused bits of the last character must be zero
+            if digit & 0x0f != 0 {
+                return Err(Invalid::Char(i));
+            }
+
+            value = (value << 2) | (digit >> 4) as u128;
+        }
+
+        i += 1;
+    }
+
+    Ok(Uuid::from_u128(value))
+}
+
+impl FromStr for Base64Url {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Base64Url::parse_str(s)
+    }
+}
+
+impl fmt::Display for Base64Url {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl_fmt_from! {
+    Base64Url<>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    const VALID: &[(&str, &str)] = &[
+        (
+            "00000000-0000-0000-0000-000000000000",
+            "AAAAAAAAAAAAAAAAAAAAAA",
+        ),
+        (
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "_____________________w",
+        ),
+        (
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "Z-VQRBCxQm-SR7toDl_gyA",
+        ),
+        (
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            "-RaMXs6yT6q2vzKb85-h5A",
+        ),
+        (
+            "936da01f-9abd-4d9d-80c7-02af85c822a8",
+            "k22gH5q9TZ2AxwKvhcgiqA",
+        ),
+    ];
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_roundtrip() {
+        for (uuid, encoded) in VALID {
+            let uuid = Uuid::parse_str(uuid).unwrap();
+
+            assert_eq!(*encoded, uuid.base64url().to_string());
+            assert_eq!(*encoded, uuid.as_base64url().to_string());
+
+            assert_eq!(uuid, Base64Url::parse_str(encoded).unwrap().into_uuid());
+            assert_eq!(uuid, Base64Url::try_parse(encoded).unwrap().into_uuid());
+            assert_eq!(uuid, encoded.parse::<Base64Url>().unwrap().into_uuid());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_invalid() {
+        assert_eq!(
+            "invalid length: expected length 22 for base64url format, found 24",
+            Base64Url::parse_str("Z-VQRBCxQm-SR7toDl_gyA==")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "invalid length: expected length 22 for base64url format, found 21",
+            Base64Url::parse_str("Z-VQRBCxQm-SR7toDl_gy")
+                .unwrap_err()
+                .to_string()
+        );
+
+        // The standard base64 alphabet isn't accepted
+        assert_eq!(
+            "invalid character: found `+` at 2",
+            Base64Url::parse_str("Z+VQRBCxQm-SR7toDl_gyA")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "invalid character: found `é` at 3",
+            Base64Url::parse_str("Z-éQRBCxQm-SR7toDl_gy")
+                .unwrap_err()
+                .to_string()
+        );
+
+        // The unused bits of the last character are set
+        assert_eq!(
+            "invalid character: found `B` at 22",
+            Base64Url::parse_str("Z-VQRBCxQm-SR7toDl_gyB")
+                .unwrap_err()
+                .to_string()
+        );
+
+        assert!(Base64Url::try_parse("Z-VQRBCxQm-SR7toDl_gyB").is_err());
+        assert!(Base64Url::try_parse("").is_err());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_encode_buffer() {
+        let uuid = Uuid::parse_str(VALID[2].0).unwrap();
+
+        assert_eq!(
+            VALID[2].1,
+            uuid.base64url().encode(&mut Uuid::encode_buffer())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_small_buffer() {
+        Uuid::nil()
+            .base64url()
+            .encode(&mut [0; Base64Url::LENGTH - 1]);
+    }
+}
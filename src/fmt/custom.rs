@@ -0,0 +1,622 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{
+    error::*,
+    parser::HEX_TABLE,
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+/// A layout for formatting and parsing a [`Uuid`] with [`Custom`].
+///
+/// A spec splits the 32 hex digits of a UUID into groups, joins them with a
+/// separator, and wraps them in a prefix and suffix. Specs are built with
+/// `const` methods, so they can be defined as constants and shared.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::fmt::Spec;
+/// const COLONS: Spec = Spec::new()
+///     .with_groups(&[8, 4, 4, 4, 12])
+///     .with_separator(":");
+///
+/// assert_eq!(36, COLONS.length());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Spec<'a> {
+    groups: &'a [u8],
+    separator: &'a str,
+    prefix: &'a str,
+    suffix: &'a str,
+    upper: bool,
+}
+
+impl<'a> Spec<'a> {
+    /// The spec for a simple UUID: a single group of 32 lower-case hex digits.
+    pub const fn new() -> Self {
+        Spec {
+            groups: &[32],
+            separator: "",
+            prefix: "",
+            suffix: "",
+            upper: false,
+        }
+    }
+
+    /// Split the hex digits into groups of the given sizes.
+    ///
+    /// Use [`Spec::try_with_groups`] for group sizes that aren't known until runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any group is empty, or if the sizes don't add up to 32.
+    /// When the spec is a `const`, this is a compile error instead.
+    pub const fn with_groups(self, groups: &'a [u8]) -> Self {
+        match self.try_with_groups(groups) {
+            Ok(spec) => spec,
+            Err(_) => panic!("custom format groups must be non-empty and add up to 32 digits"),
+        }
+    }
+
+    /// Split the hex digits into groups of the given sizes, returning an error
+    /// if any group is empty, or if the sizes don't add up to 32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::fmt::Spec;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let spec = Spec::new().try_with_groups(&[8, 8, 8, 8])?;
+    ///
+    /// assert_eq!(&[8, 8, 8, 8], spec.groups());
+    ///
+    /// assert!(Spec::new().try_with_groups(&[8, 8, 8]).is_err());
+    /// assert!(Spec::new().try_with_groups(&[16, 0, 16]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn try_with_groups(mut self, groups: &'a [u8]) -> Result<Self, Error> {
+        let mut total = 0;
+
+        let mut i = 0;
+        while i < groups.len() {
+            if groups[i] == 0 {
+                return Err(Error(ErrorKind::CustomGroups));
+            }
+
+            total += groups[i] as usize;
+            i += 1;
+        }
+
+        if total != 32 {
+            return Err(Error(ErrorKind::CustomGroups));
+        }
+
+        self.groups = groups;
+        Ok(self)
+    }
+
+    /// Join the groups with the given separator.
+    pub const fn with_separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Write the given prefix before the first group.
+    pub const fn with_prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Write the given suffix after the last group.
+    pub const fn with_suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Format hex digits as upper-case.
+    ///
+    /// Parsing accepts hex digits in either case.
+    pub const fn with_upper_case(mut self) -> Self {
+        self.upper = true;
+        self
+    }
+
+    /// The sizes of the groups of hex digits.
+    pub const fn groups(&self) -> &'a [u8] {
+        self.groups
+    }
+
+    /// The length of a [`Uuid`] formatted with this spec.
+    pub const fn length(&self) -> usize {
+        self.prefix.len() + 32 + self.separator.len() * (self.groups.len() - 1) + self.suffix.len()
+    }
+}
+
+impl Default for Spec<'_> {
+    fn default() -> Self {
+        Spec::new()
+    }
+}
+
+/// Format a [`Uuid`] using a [`Spec`], like
+/// `{0x67e55044:10b1:426f:9247:bb680e5fe0c8}`.
+///
+/// Parsing uses the same spec as formatting. Hex digits are accepted in
+/// either case, but the prefix, suffix, and separators must match exactly.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::{Custom, Spec}, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// const LEGACY: Spec = Spec::new()
+///     .with_groups(&[8, 4, 4, 4, 12])
+///     .with_separator(":")
+///     .with_prefix("{0x")
+///     .with_suffix("}");
+///
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+///
+/// assert_eq!("{0x67e55044:10b1:426f:9247:bb680e5fe0c8}", uuid.custom(&LEGACY).to_string());
+///
+/// let parsed = Custom::parse_str("{0x67E55044:10B1:426F:9247:BB680E5FE0C8}", &LEGACY)?;
+///
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Custom<'spec> {
+    uuid: Uuid,
+    spec: &'spec Spec<'spec>,
+}
+
+impl Uuid {
+    /// Get a [`Custom`] formatter using the given [`Spec`].
+    #[inline]
+    pub const fn custom<'spec>(self, spec: &'spec Spec<'spec>) -> Custom<'spec> {
+        Custom::from_uuid(self, spec)
+    }
+}
+
+impl<'spec> Custom<'spec> {
+    /// Creates a [`Custom`] from a [`Uuid`] and a [`Spec`].
+    pub const fn from_uuid(uuid: Uuid, spec: &'spec Spec<'spec>) -> Self {
+        Custom { uuid, spec }
+    }
+
+    /// Parses a string using the given [`Spec`].
+    ///
+    /// The input must be exactly [`Spec::length`] bytes long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::{Custom, Spec}, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// const QUADS: Spec = Spec::new().with_groups(&[4; 8]).with_separator(" ");
+    ///
+    /// assert_eq!(
+    ///     Uuid::max(),
+    ///     Custom::parse_str("ffff ffff ffff ffff ffff ffff ffff ffff", &QUADS)?.into_uuid(),
+    /// );
+    ///
+    /// assert!(Custom::parse_str("ffff-ffff-ffff-ffff-ffff-ffff-ffff-ffff", &QUADS).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(input: &str, spec: &'spec Spec<'spec>) -> Result<Self, Error> {
+        match decode(input.as_bytes(), spec) {
+            Ok(uuid) => Ok(Custom { uuid, spec }),
+            Err(Invalid::Length) => Err(Error(ErrorKind::CustomLength {
+                expected: spec.length(),
+                len: input.len(),
+            })),
+            Err(Invalid::Char(mut index)) => {
+                // A literal in the spec may only partially match a multi-byte character
+                while !input.is_char_boundary(index) {
+                    index -= 1;
+                }
+
+                Err(Error(ErrorKind::ParseChar {
+                    character: input[index..].chars().next().unwrap(),
+                    index: index + 1,
+                }))
+            }
+        }
+    }
+
+    /// Parses a string using the given [`Spec`] in a `const` context.
+    ///
+    /// This method accepts the same input as [`Custom::parse_str`], but
+    /// won't generate very useful error messages if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::fmt::{Custom, Spec};
+    /// const SPEC: Spec = Spec::new().with_upper_case();
+    ///
+    /// const ID: Custom = match Custom::try_parse("67E5504410B1426F9247BB680E5FE0C8", &SPEC) {
+    ///     Ok(id) => id,
+    ///     Err(_) => panic!("invalid UUID"),
+    /// };
+    ///
+    /// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", ID.as_uuid().to_string());
+    /// ```
+    pub const fn try_parse(input: &str, spec: &'spec Spec<'spec>) -> Result<Self, Error> {
+        match decode(input.as_bytes(), spec) {
+            Ok(uuid) => Ok(Custom { uuid, spec }),
+            Err(_) => Err(Error(ErrorKind::ParseOther)),
+        }
+    }
+
+    /// Writes the [`Uuid`] using its [`Spec`] to `buffer`, and returns the subslice
+    /// of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`Spec::length`]. [`Uuid::encode_buffer`] can be used to get a temporary
+    /// buffer for specs up to 45 bytes long.
+    ///
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Spec, Uuid};
+    /// const SPEC: Spec = Spec::new().with_upper_case();
+    ///
+    /// assert_eq!(
+    ///     "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+    ///     Uuid::max().custom(&SPEC).encode(&mut Uuid::encode_buffer()),
+    /// );
+    /// ```
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let spec = self.spec;
+        let buf = &mut buffer[..spec.length()];
+
+        let mut i = 0;
+        self.write_parts(|part| {
+            buf[i..i + part.len()].copy_from_slice(part.as_bytes());
+            i += part.len();
+        });
+
+        // SAFETY: The buffer is made up of the spec's strings and ASCII hex digits
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// The [`Spec`] used to encode the [`Uuid`].
+    pub const fn spec(&self) -> &'spec Spec<'spec> {
+        self.spec
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Consumes the [`Custom`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.uuid
+    }
+
+    // Calls `write` with each piece of the formatted UUID in order
+    fn write_parts(&self, mut write: impl FnMut(&str)) {
+        let spec = self.spec;
+        let hex = super::format_simple(self.uuid.as_bytes(), spec.upper);
+
+        write(spec.prefix);
+
+        let mut start = 0;
+        for (i, &group) in spec.groups.iter().enumerate() {
+            if i > 0 {
+                write(spec.separator);
+            }
+
+            let end = start + group as usize;
+
+            // SAFETY: The hex digits are ASCII encoded
+            write(unsafe { str::from_utf8_unchecked(&hex[start..end]) });
+
+            start = end;
+        }
+
+        write(spec.suffix);
+    }
+}
+
+enum Invalid {
+    Length,
+    Char(usize),
+}
+
+const fn decode(input: &[u8], spec: &Spec<'_>) -> Result<Uuid, Invalid> {
+    if input.len() != spec.length() {
+        return Err(Invalid::Length);
+    }
+
+    let mut i = match match_literal(input, 0, spec.prefix.as_bytes()) {
+        Ok(i) => i,
+        Err(err) => return Err(err),
+    };
+
+    let mut value = 0u128;
+
+    let mut g = 0;
+    while g < spec.groups.len() {
+        if g > 0 {
+            i = match match_literal(input, i, spec.separator.as_bytes()) {
+                Ok(i) => i,
+                Err(err) => return Err(err),
+            };
+        }
+
+        let end = i + spec.groups[g] as usize;
+        while i < end {
+            let digit = HEX_TABLE[input[i] as usize];
+            if digit == 0xff {
+                return Err(Invalid::Char(i));
+            }
+
+            value = (value << 4) | digit as u128;
+            i += 1;
+        }
+
+        g += 1;
+    }
+
+    match match_literal(input, i, spec.suffix.as_bytes()) {
+        Ok(_) => Ok(Uuid::from_u128(value)),
+        Err(err) => Err(err),
+    }
+}
+
+// Checks that `literal` appears in `input` at `start`, returning the index after it
+const fn match_literal(input: &[u8], start: usize, literal: &[u8]) -> Result<usize, Invalid> {
+    let mut i = 0;
+    while i < literal.len() {
+        if input[start + i] != literal[i] {
+            return Err(Invalid::Char(start + i));
+        }
+
+        i += 1;
+    }
+
+    Ok(start + i)
+}
+
+impl fmt::Display for Custom<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = Ok(());
+        self.write_parts(|part| {
+            if result.is_ok() {
+                result = f.write_str(part);
+            }
+        });
+
+        result
+    }
+}
+
+impl From<Custom<'_>> for Uuid {
+    #[inline]
+    fn from(f: Custom<'_>) -> Self {
+        f.into_uuid()
+    }
+}
+
+impl AsRef<Uuid> for Custom<'_> {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+impl Borrow<Uuid> for Custom<'_> {
+    #[inline]
+    fn borrow(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    const COLONS: Spec = Spec::new()
+        .with_groups(&[8, 4, 4, 4, 12])
+        .with_separator(":");
+    const BRACED: Spec = Spec::new().with_prefix("{0x").with_suffix("}");
+    const UPPER: Spec = Spec::new().with_upper_case();
+    const QUADS: Spec = Spec::new().with_groups(&[4; 8]).with_separator("-");
+    const GUILLEMETS: Spec = Spec::new()
+        .with_groups(&[16, 16])
+        .with_separator("·")
+        .with_prefix("«")
+        .with_suffix("»");
+
+    const VALID: &[(&Spec, &str)] = &[
+        (&COLONS, "67e55044:10b1:426f:9247:bb680e5fe0c8"),
+        (&BRACED, "{0x67e5504410b1426f9247bb680e5fe0c8}"),
+        (&UPPER, "67E5504410B1426F9247BB680E5FE0C8"),
+        (&QUADS, "67e5-5044-10b1-426f-9247-bb68-0e5f-e0c8"),
+        (&GUILLEMETS, "«67e5504410b1426f·9247bb680e5fe0c8»"),
+    ];
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_roundtrip() {
+        let uuid = Uuid::parse_str(UUID).unwrap();
+
+        for (spec, encoded) in VALID {
+            assert_eq!(encoded.len(), spec.length());
+
+            assert_eq!(*encoded, uuid.custom(spec).to_string());
+            assert_eq!(*encoded, uuid.custom(spec).encode(&mut [0; 64]));
+
+            assert_eq!(uuid, Custom::parse_str(encoded, spec).unwrap().into_uuid());
+            assert_eq!(uuid, Custom::try_parse(encoded, spec).unwrap().into_uuid());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_default_is_simple() {
+        let uuid = Uuid::parse_str(UUID).unwrap();
+
+        assert_eq!(Spec::new(), Spec::default());
+        assert_eq!(
+            uuid.simple().to_string(),
+            uuid.custom(&Spec::new()).to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_parse_any_case() {
+        let uuid = Uuid::parse_str(UUID).unwrap();
+
+        assert_eq!(
+            uuid,
+            Custom::parse_str("67E55044:10B1:426F:9247:BB680E5FE0C8", &COLONS)
+                .unwrap()
+                .into_uuid()
+        );
+        assert_eq!(
+            uuid,
+            Custom::parse_str("67e5504410b1426f9247bb680e5fe0c8", &UPPER)
+                .unwrap()
+                .into_uuid()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_invalid() {
+        assert_eq!(
+            Error(ErrorKind::CustomLength {
+                expected: 36,
+                len: 32
+            }),
+            Custom::parse_str("67e5504410b1426f9247bb680e5fe0c8", &COLONS).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: '-',
+                index: 9
+            }),
+            Custom::parse_str(UUID, &COLONS).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: 'X',
+                index: 3
+            }),
+            Custom::parse_str("{0X67e5504410b1426f9247bb680e5fe0c8}", &BRACED).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: ')',
+                index: 36
+            }),
+            Custom::parse_str("{0x67e5504410b1426f9247bb680e5fe0c8)", &BRACED).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: 'g',
+                index: 32
+            }),
+            Custom::parse_str("67E5504410B1426F9247BB680E5FE0Cg", &UPPER).unwrap_err()
+        );
+        // `¬` shares its first byte with `«`
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: '¬',
+                index: 1
+            }),
+            Custom::parse_str("¬67e5504410b1426f·9247bb680e5fe0c8»", &GUILLEMETS).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseOther),
+            Custom::try_parse(UUID, &COLONS).unwrap_err()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_try_with_groups() {
+        assert_eq!(
+            QUADS,
+            Spec::new()
+                .try_with_groups(&[4; 8])
+                .unwrap()
+                .with_separator("-")
+        );
+
+        for groups in [
+            &[][..],
+            &[8, 4, 4, 4, 8],
+            &[16, 0, 16],
+            &[32, 1],
+            &[255, 33],
+        ] {
+            assert_eq!(
+                Error(ErrorKind::CustomGroups),
+                Spec::new().try_with_groups(groups).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_groups() {
+        let _ = Spec::new().with_groups(&[8, 4, 4, 4, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_group() {
+        let _ = Spec::new().with_groups(&[16, 0, 16]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_small_buffer() {
+        Uuid::max().custom(&COLONS).encode(&mut [0; 35]);
+    }
+}
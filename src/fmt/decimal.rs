@@ -0,0 +1,336 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::str::FromStr;
+
+use crate::{
+    error::*,
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+/// Format a [`Uuid`] as a decimal integer, like
+/// `329800735698586629295641978511506172918`.
+///
+/// The UUID is encoded as a 128-bit big-endian number, like [`Uuid::as_u128`],
+/// without leading zeros. The result is between 1 and 39 digits long. This is
+/// useful for storing UUIDs in databases that don't have a UUID type, but can
+/// store large integers.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::Decimal, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")?;
+///
+/// assert_eq!("329800735698586629295641978511506172918", uuid.decimal().to_string());
+///
+/// let parsed: Decimal = "329800735698586629295641978511506172918".parse()?;
+///
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    all(uuid_unstable, feature = "zerocopy"),
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct Decimal(Uuid);
+
+impl Uuid {
+    /// Get a [`Decimal`] formatter.
+    #[inline]
+    pub const fn decimal(self) -> Decimal {
+        Decimal(self)
+    }
+
+    /// Get a borrowed [`Decimal`] formatter.
+    #[inline]
+    pub fn as_decimal(&self) -> &Decimal {
+        unsafe_transmute_ref!(self)
+    }
+}
+
+impl Decimal {
+    /// The length of the longest decimal [`Uuid`] string.
+    pub const LENGTH: usize = 39;
+
+    /// Creates a [`Decimal`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Decimal(uuid)
+    }
+
+    /// Parses a decimal string.
+    ///
+    /// The input must be between 1 and 39 ASCII digits, and encode a value that
+    /// fits in 128 bits. Leading zeros are allowed, so values stored in zero-padded
+    /// columns can be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Decimal, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// assert_eq!(Uuid::from_u128(42), Decimal::parse_str("00042")?.into_uuid());
+    ///
+    /// assert!(Decimal::parse_str("340282366920938463463374607431768211456").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        match decode_u128(input.as_bytes()) {
+            Ok(value) => Ok(Decimal(Uuid::from_u128(value))),
+            Err(Invalid::Length) => Err(Error(ErrorKind::DecimalLength { len: input.len() })),
+            Err(Invalid::Char(index)) => Err(Error(ErrorKind::ParseChar {
+                // Every byte before the index is ASCII, so it's at a character boundary
+                character: input[index..].chars().next().unwrap(),
+                index: index + 1,
+            })),
+            Err(Invalid::Overflow) => Err(Error(ErrorKind::DecimalOverflow)),
+        }
+    }
+
+    /// Parses a decimal string in a `const` context.
+    ///
+    /// This method accepts the same input as [`Decimal::parse_str`], but
+    /// won't generate very useful error messages if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Decimal, Uuid};
+    /// const ID: Decimal = match Decimal::try_parse("329800735698586629295641978511506172918") {
+    ///     Ok(id) => id,
+    ///     Err(_) => panic!("invalid decimal"),
+    /// };
+    ///
+    /// assert_eq!("f81d4fae-7dec-11d0-a765-00a0c91e6bf6", ID.as_uuid().to_string());
+    /// ```
+    pub const fn try_parse(input: &str) -> Result<Self, Error> {
+        match decode_u128(input.as_bytes()) {
+            Ok(value) => Ok(Decimal(Uuid::from_u128(value))),
+            Err(_) => Err(Error(ErrorKind::ParseOther)),
+        }
+    }
+
+    /// Writes the [`Uuid`] as a decimal string to `buffer`, and returns the subslice
+    /// of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// assert_eq!("0", Uuid::nil().decimal().encode(&mut Uuid::encode_buffer()));
+    /// assert_eq!(
+    ///     "340282366920938463463374607431768211455",
+    ///     Uuid::max().decimal().encode(&mut Uuid::encode_buffer()),
+    /// );
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let buf = &mut buffer[..Self::LENGTH];
+        let buf: &mut [u8; Decimal::LENGTH] = buf.try_into().unwrap();
+
+        let start = format_u128(self.0.as_u128(), buf);
+        buf.copy_within(start.., 0);
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(&mut buf[..Self::LENGTH - start]) }
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Decimal`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+// Writes the digits of `value` to the end of `dst`, returning the index of the first one
+pub(super) const fn format_u128(mut value: u128, dst: &mut [u8; Decimal::LENGTH]) -> usize {
+    let mut start = Decimal::LENGTH;
+
+    loop {
+        start -= 1;
+        dst[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+
+        if value == 0 {
+            return start;
+        }
+    }
+}
+
+pub(super) enum Invalid {
+    Length,
+    Char(usize),
+    Overflow,
+}
+
+pub(super) const fn decode_u128(input: &[u8]) -> Result<u128, Invalid> {
+    if input.is_empty() || input.len() > Decimal::LENGTH {
+        return Err(Invalid::Length);
+    }
+
+    let mut value = 0u128;
+
+    let mut i = 0;
+    while i < input.len() {
+        if !input[i].is_ascii_digit() {
+            return Err(Invalid::Char(i));
+        }
+
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((input[i] - b'0') as u128) {
+                Some(value) => value,
+                None => return Err(Invalid::Overflow),
+            },
+            None => return Err(Invalid::Overflow),
+        };
+
+        i += 1;
+    }
+
+    Ok(value)
+}
+
+impl FromStr for Decimal {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::parse_str(s)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl_fmt_from! {
+    Decimal<>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    const VALID: &[(&str, &str)] = &[
+        ("00000000-0000-0000-0000-000000000000", "0"),
+        ("00000000-0000-0000-0000-00000000000a", "10"),
+        (
+            "f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+            "329800735698586629295641978511506172918",
+        ),
+        (
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "138101147531900207301164854559698313416",
+        ),
+        (
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "340282366920938463463374607431768211455",
+        ),
+    ];
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_roundtrip() {
+        for (uuid, encoded) in VALID {
+            let uuid = Uuid::parse_str(uuid).unwrap();
+
+            assert_eq!(*encoded, uuid.decimal().to_string());
+            assert_eq!(*encoded, uuid.as_decimal().to_string());
+            assert_eq!(uuid.as_u128().to_string(), uuid.decimal().to_string());
+
+            assert_eq!(uuid, Decimal::parse_str(encoded).unwrap().into_uuid());
+            assert_eq!(uuid, Decimal::try_parse(encoded).unwrap().into_uuid());
+            assert_eq!(uuid, encoded.parse::<Decimal>().unwrap().into_uuid());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_invalid() {
+        assert_eq!(
+            Error(ErrorKind::DecimalLength { len: 0 }),
+            Decimal::parse_str("").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::DecimalLength { len: 40 }),
+            Decimal::parse_str("0000000000000000000000000000000000000000").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: '-',
+                index: 1
+            }),
+            Decimal::parse_str("-1").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: '٣',
+                index: 3
+            }),
+            Decimal::parse_str("12٣").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::DecimalOverflow),
+            Decimal::parse_str("340282366920938463463374607431768211456").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::DecimalOverflow),
+            Decimal::parse_str("999999999999999999999999999999999999999").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseOther),
+            Decimal::try_parse("+1").unwrap_err()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_small_buffer() {
+        Uuid::max().decimal().encode(&mut [0; 38]);
+    }
+}
@@ -0,0 +1,522 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::str::FromStr;
+
+use super::decimal::{self, Decimal};
+use crate::{
+    error::*,
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+// The `joint-iso-itu-t(2) uuid(25)` arc every UUID is an OID under
+const PREFIX: &[u8] = b"2.25.";
+
+// The DER tag of an object identifier
+const DER_TAG: u8 = 0x06;
+// The first two arcs are encoded in one subidentifier as `40 * 2 + 25`
+const DER_PREFIX: u8 = 0x69;
+
+/// Format a [`Uuid`] as an object identifier (OID), like
+/// `2.25.329800735698586629295641978511506172918`.
+///
+/// ITU-T X.667 assigns every UUID an OID under the `2.25` arc, with the UUID as
+/// a [decimal](super::Decimal) integer without leading zeros for its last arc. The
+/// result is between 6 and 44 characters long. OIDs can also be encoded in DER with
+/// [`Oid::encode_der`], to embed in certificates and other ASN.1 structures.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::Oid, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")?;
+///
+/// assert_eq!("2.25.329800735698586629295641978511506172918", uuid.oid().to_string());
+///
+/// let parsed: Oid = "2.25.329800735698586629295641978511506172918".parse()?;
+///
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///
+/// * [ITU-T X.667](https://www.itu.int/rec/T-REC-X.667)
+/// * [ITU-T X.690](https://www.itu.int/rec/T-REC-X.690)
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    all(uuid_unstable, feature = "zerocopy"),
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct Oid(Uuid);
+
+impl Uuid {
+    /// Get an [`Oid`] formatter.
+    #[inline]
+    pub const fn oid(self) -> Oid {
+        Oid(self)
+    }
+
+    /// Get a borrowed [`Oid`] formatter.
+    #[inline]
+    pub fn as_oid(&self) -> &Oid {
+        unsafe_transmute_ref!(self)
+    }
+}
+
+impl Oid {
+    /// The length of the longest OID [`Uuid`] string.
+    pub const LENGTH: usize = 44;
+
+    /// The length of the longest DER encoded OID [`Uuid`].
+    ///
+    /// This is the tag and length bytes, followed by a byte for the `2.25` arcs,
+    /// and up to 19 bytes of 7-bit groups for the UUID arc.
+    pub const DER_LENGTH: usize = 22;
+
+    /// Creates an [`Oid`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Oid(uuid)
+    }
+
+    /// Parses an OID string.
+    ///
+    /// The input must be `2.25.` followed by between 1 and 39 ASCII digits that encode
+    /// a value that fits in 128 bits. The last arc can't have leading zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Oid, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// assert_eq!(Uuid::nil(), Oid::parse_str("2.25.0")?.into_uuid());
+    ///
+    /// assert!(Oid::parse_str("2.25.042").is_err());
+    /// assert!(Oid::parse_str("1.2.840.113549").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(Oid(uuid)),
+            Err(Invalid::Prefix) => Err(Error(ErrorKind::OidPrefix)),
+            Err(Invalid::LeadingZero) => Err(Error(ErrorKind::OidLeadingZero)),
+            Err(Invalid::Decimal(decimal::Invalid::Length)) => {
+                Err(Error(ErrorKind::OidLength { len: input.len() }))
+            }
+            Err(Invalid::Decimal(decimal::Invalid::Char(index))) => {
+                let index = PREFIX.len() + index;
+
+                Err(Error(ErrorKind::ParseChar {
+                    // Every byte before the index is ASCII, so it's at a character boundary
+                    character: input[index..].chars().next().unwrap(),
+                    index: index + 1,
+                }))
+            }
+            Err(Invalid::Decimal(decimal::Invalid::Overflow)) => {
+                Err(Error(ErrorKind::DecimalOverflow))
+            }
+        }
+    }
+
+    /// Parses an OID string in a `const` context.
+    ///
+    /// This method accepts the same input as [`Oid::parse_str`], but
+    /// won't generate very useful error messages if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Oid, Uuid};
+    /// const ID: Oid = match Oid::try_parse("2.25.329800735698586629295641978511506172918") {
+    ///     Ok(id) => id,
+    ///     Err(_) => panic!("invalid OID"),
+    /// };
+    ///
+    /// assert_eq!("f81d4fae-7dec-11d0-a765-00a0c91e6bf6", ID.as_uuid().to_string());
+    /// ```
+    pub const fn try_parse(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(Oid(uuid)),
+            Err(_) => Err(Error(ErrorKind::ParseOther)),
+        }
+    }
+
+    /// Parses a DER encoded OID.
+    ///
+    /// The input must be a complete DER encoding of an OID under the `2.25` arc, including
+    /// its tag and length, like the output of [`Oid::encode_der`]. Non-minimal encodings
+    /// aren't accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Oid, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// assert_eq!(Uuid::from_u128(300), Oid::from_der(&[0x06, 0x03, 0x69, 0x82, 0x2c])?.into_uuid());
+    ///
+    /// // The leading `0x80` isn't minimal
+    /// assert!(Oid::from_der(&[0x06, 0x04, 0x69, 0x80, 0x82, 0x2c]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn from_der(input: &[u8]) -> Result<Self, Error> {
+        match decode_der(input) {
+            Some(uuid) => Ok(Oid(uuid)),
+            None => Err(Error(ErrorKind::OidDer)),
+        }
+    }
+
+    /// Writes the [`Uuid`] as an OID string to `buffer`, and returns the subslice
+    /// of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// assert_eq!("2.25.0", Uuid::nil().oid().encode(&mut Uuid::encode_buffer()));
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let buf = &mut buffer[..Self::LENGTH];
+
+        let mut digits = [0; Decimal::LENGTH];
+        let start = decimal::format_u128(self.0.as_u128(), &mut digits);
+        let len = PREFIX.len() + Decimal::LENGTH - start;
+
+        buf[..PREFIX.len()].copy_from_slice(PREFIX);
+        buf[PREFIX.len()..len].copy_from_slice(&digits[start..]);
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(&mut buf[..len]) }
+    }
+
+    /// Writes the [`Uuid`] as a DER encoded OID to `buffer`, and returns the subslice
+    /// of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`DER_LENGTH`].
+    ///
+    /// [`DER_LENGTH`]: #associatedconstant.DER_LENGTH
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Oid, Uuid};
+    /// let mut buf = [0; Oid::DER_LENGTH];
+    ///
+    /// assert_eq!(
+    ///     &[0x06, 0x03, 0x69, 0x82, 0x2c],
+    ///     Uuid::from_u128(300).oid().encode_der(&mut buf),
+    /// );
+    /// ```
+    pub fn encode_der<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut [u8] {
+        let buf = &mut buffer[..Self::DER_LENGTH];
+        let buf: &mut [u8; Oid::DER_LENGTH] = buf.try_into().unwrap();
+
+        let len = format_der(self.0.as_u128(), buf);
+
+        &mut buf[..len]
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Oid`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+// Writes the DER encoding to the start of `dst`, returning its length
+const fn format_der(value: u128, dst: &mut [u8; Oid::DER_LENGTH]) -> usize {
+    // The number of 7-bit groups needed for the value, with at least one for zero
+    let bits = 128 - value.leading_zeros() as usize;
+    let groups = if bits == 0 { 1 } else { bits.div_ceil(7) };
+
+    dst[0] = DER_TAG;
+    dst[1] = (groups + 1) as u8;
+    dst[2] = DER_PREFIX;
+
+    // Each group except the last has its most significant bit set
+    let mut i = 0;
+    while i < groups {
+        let shift = (groups - 1 - i) * 7;
+        let continuation = if i < groups - 1 { 0x80 } else { 0 };
+
+        dst[3 + i] = ((value >> shift) & 0x7f) as u8 | continuation;
+        i += 1;
+    }
+
+    3 + groups
+}
+
+const fn decode_der(input: &[u8]) -> Option<Uuid> {
+    if input.len() < 4
+        || input.len() > Oid::DER_LENGTH
+        || input[0] != DER_TAG
+        || input[1] as usize != input.len() - 2
+        || input[2] != DER_PREFIX
+        // The first group can't be padding
+        || input[3] == 0x80
+    {
+        return None;
+    }
+
+    let mut value = 0u128;
+
+    let mut i = 3;
+    while i < input.len() {
+        let last = i == input.len() - 1;
+
+        // Only the last group has its most significant bit unset
+        if (input[i] & 0x80 == 0) != last {
+            return None;
+        }
+
+        // The value must fit in 128 bits
+        if value >> 121 != 0 {
+            return None;
+        }
+
+        value = (value << 7) | (input[i] & 0x7f) as u128;
+        i += 1;
+    }
+
+    Some(Uuid::from_u128(value))
+}
+
+enum Invalid {
+    Prefix,
+    LeadingZero,
+    Decimal(decimal::Invalid),
+}
+
+const fn decode(input: &[u8]) -> Result<Uuid, Invalid> {
+    if input.len() < PREFIX.len() {
+        return Err(Invalid::Prefix);
+    }
+
+    let (prefix, digits) = input.split_at(PREFIX.len());
+
+    let mut i = 0;
+    while i < PREFIX.len() {
+        if prefix[i] != PREFIX[i] {
+            return Err(Invalid::Prefix);
+        }
+
+        i += 1;
+    }
+
+    if digits.len() > 1 && digits[0] == b'0' {
+        return Err(Invalid::LeadingZero);
+    }
+
+    match decimal::decode_u128(digits) {
+        Ok(value) => Ok(Uuid::from_u128(value)),
+        Err(err) => Err(Invalid::Decimal(err)),
+    }
+}
+
+impl FromStr for Oid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Oid::parse_str(s)
+    }
+}
+
+impl fmt::Display for Oid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl_fmt_from! {
+    Oid<>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    const VALID: &[(&str, &str, &[u8])] = &[
+        (
+            "00000000-0000-0000-0000-000000000000",
+            "2.25.0",
+            &[0x06, 0x02, 0x69, 0x00],
+        ),
+        (
+            "00000000-0000-0000-0000-00000000007f",
+            "2.25.127",
+            &[0x06, 0x02, 0x69, 0x7f],
+        ),
+        (
+            "00000000-0000-0000-0000-000000000080",
+            "2.25.128",
+            &[0x06, 0x03, 0x69, 0x81, 0x00],
+        ),
+        (
+            "f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+            "2.25.329800735698586629295641978511506172918",
+            &[
+                0x06, 0x14, 0x69, 0x83, 0xf0, 0x9d, 0xa7, 0xeb, 0xcf, 0xde, 0xe0, 0xc7, 0xa1, 0xa7,
+                0xb2, 0xc0, 0x94, 0x8c, 0xc8, 0xf9, 0xd7, 0x76,
+            ],
+        ),
+        (
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "2.25.340282366920938463463374607431768211455",
+            &[
+                0x06, 0x14, 0x69, 0x83, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+                0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
+            ],
+        ),
+    ];
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_roundtrip() {
+        for (uuid, encoded, der) in VALID {
+            let uuid = Uuid::parse_str(uuid).unwrap();
+
+            assert_eq!(*encoded, uuid.oid().to_string());
+            assert_eq!(*encoded, uuid.as_oid().to_string());
+            assert_eq!(*der, uuid.oid().encode_der(&mut [0; Oid::DER_LENGTH]));
+
+            assert_eq!(uuid, Oid::parse_str(encoded).unwrap().into_uuid());
+            assert_eq!(uuid, Oid::try_parse(encoded).unwrap().into_uuid());
+            assert_eq!(uuid, encoded.parse::<Oid>().unwrap().into_uuid());
+            assert_eq!(uuid, Oid::from_der(der).unwrap().into_uuid());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_invalid() {
+        for (input, err) in [
+            ("", ErrorKind::OidPrefix),
+            ("2.25", ErrorKind::OidPrefix),
+            ("2.26.1", ErrorKind::OidPrefix),
+            ("urn:oid:2.25.1", ErrorKind::OidPrefix),
+            ("2.25.", ErrorKind::OidLength { len: 5 }),
+            (
+                "2.25.3298007356985866292956419785115061729180",
+                ErrorKind::OidLength { len: 45 },
+            ),
+            ("2.25.00", ErrorKind::OidLeadingZero),
+            (
+                "2.25.0329800735698586629295641978511506172918",
+                ErrorKind::OidLeadingZero,
+            ),
+            (
+                "2.25.1.2",
+                ErrorKind::ParseChar {
+                    character: '.',
+                    index: 7,
+                },
+            ),
+            (
+                "2.25.340282366920938463463374607431768211456",
+                ErrorKind::DecimalOverflow,
+            ),
+        ] {
+            assert_eq!(Error(err), Oid::parse_str(input).unwrap_err(), "{input}");
+        }
+
+        assert_eq!(
+            Error(ErrorKind::ParseOther),
+            Oid::try_parse("2.25.-1").unwrap_err()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_invalid_der() {
+        for der in [
+            &[][..],
+            &[0x06, 0x01, 0x69],
+            // Not an OID
+            &[0x04, 0x02, 0x69, 0x00],
+            // Not under `2.25`
+            &[0x06, 0x02, 0x68, 0x00],
+            // Length mismatch
+            &[0x06, 0x03, 0x69, 0x00],
+            &[0x06, 0x02, 0x69, 0x00, 0x00],
+            // Padded first group
+            &[0x06, 0x03, 0x69, 0x80, 0x00],
+            // Unterminated
+            &[0x06, 0x02, 0x69, 0x81],
+            // More than one arc
+            &[0x06, 0x03, 0x69, 0x01, 0x02],
+            // Larger than 128 bits
+            &[
+                0x06, 0x14, 0x69, 0x84, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+                0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00,
+            ],
+        ] {
+            assert_eq!(
+                Error(ErrorKind::OidDer),
+                Oid::from_der(der).unwrap_err(),
+                "{der:02x?}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_small_buffer() {
+        Uuid::max().oid().encode(&mut [0; 43]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_der_small_buffer() {
+        Uuid::nil().oid().encode_der(&mut [0; 21]);
+    }
+}
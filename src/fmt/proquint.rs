@@ -0,0 +1,421 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::str::FromStr;
+
+use crate::{
+    error::*,
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+// Each consonant encodes 4 bits, and each vowel 2 bits
+const CONSONANTS: &[u8; 16] = b"bdfghjklmnprstvz";
+const VOWELS: &[u8; 4] = b"aiou";
+
+// Maps an ASCII byte back to its 4-bit consonant or 2-bit vowel value, or `0xff`
+// if it's not one. Decoding is case-insensitive
+const DECODE_CONSONANT: [u8; 256] = decode_table(CONSONANTS);
+const DECODE_VOWEL: [u8; 256] = decode_table(VOWELS);
+
+const fn decode_table(letters: &[u8]) -> [u8; 256] {
+    let mut table = [0xff; 256];
+
+    let mut i = 0;
+    while i < letters.len() {
+        table[letters[i] as usize] = i as u8;
+        table[letters[i].to_ascii_uppercase() as usize] = i as u8;
+        i += 1;
+    }
+
+    table
+}
+
+/// Format a [`Uuid`] as a series of pronounceable proquint words, like
+/// `kizoj-jadah-dafud-hanoz-nanal-rotom-buniz-vagam`.
+///
+/// Each 16 bits of the UUID, starting from the most significant, are encoded as a
+/// five-letter word that alternates consonants and vowels. The eight words are
+/// separated by hyphens, so the result is always 47 characters long. Proquints are
+/// easier to read aloud and remember than hexadecimal.
+///
+/// Decoding is case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::Proquint, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+///
+/// assert_eq!(
+///     "kizoj-jadah-dafud-hanoz-nanal-rotom-buniz-vagam",
+///     uuid.proquint().to_string(),
+/// );
+///
+/// let parsed: Proquint = "KIZOJ-JADAH-DAFUD-HANOZ-NANAL-ROTOM-BUNIZ-VAGAM".parse()?;
+///
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///
+/// * [A Proposal for Proquints](https://arxiv.org/html/0901.4016)
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    all(uuid_unstable, feature = "zerocopy"),
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct Proquint(Uuid);
+
+impl Uuid {
+    /// Get a [`Proquint`] formatter.
+    #[inline]
+    pub const fn proquint(self) -> Proquint {
+        Proquint(self)
+    }
+
+    /// Get a borrowed [`Proquint`] formatter.
+    #[inline]
+    pub fn as_proquint(&self) -> &Proquint {
+        unsafe_transmute_ref!(self)
+    }
+}
+
+impl Proquint {
+    /// The length of a proquint [`Uuid`] string.
+    pub const LENGTH: usize = 47;
+
+    /// Creates a [`Proquint`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Proquint(uuid)
+    }
+
+    /// Parses a proquint string.
+    ///
+    /// The input must be exactly eight five-letter words separated by hyphens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Proquint, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// assert_eq!(
+    ///     Uuid::max(),
+    ///     Proquint::parse_str("zuzuz-zuzuz-zuzuz-zuzuz-zuzuz-zuzuz-zuzuz-zuzuz")?.into_uuid(),
+    /// );
+    ///
+    /// // `a` isn't a consonant
+    /// assert!(Proquint::parse_str("zuzua-zuzuz-zuzuz-zuzuz-zuzuz-zuzuz-zuzuz-zuzuz").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(Proquint(uuid)),
+            Err(Invalid::Length) => Err(Error(ErrorKind::ProquintLength { len: input.len() })),
+            Err(Invalid::Char(index)) => Err(Error(ErrorKind::ParseChar {
+                // Every byte before the index is ASCII, so it's at a character boundary
+                character: input[index..].chars().next().unwrap(),
+                index: index + 1,
+            })),
+        }
+    }
+
+    /// Parses a proquint string in a `const` context.
+    ///
+    /// This method accepts the same input as [`Proquint::parse_str`], but
+    /// won't generate very useful error messages if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Proquint, Uuid};
+    /// const ID: Proquint =
+    ///     match Proquint::try_parse("kizoj-jadah-dafud-hanoz-nanal-rotom-buniz-vagam") {
+    ///         Ok(id) => id,
+    ///         Err(_) => panic!("invalid proquint"),
+    ///     };
+    ///
+    /// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", ID.as_uuid().to_string());
+    /// ```
+    pub const fn try_parse(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(Proquint(uuid)),
+            Err(_) => Err(Error(ErrorKind::ParseOther)),
+        }
+    }
+
+    /// Writes the [`Uuid`] as a proquint string to `buffer`, and returns the
+    /// subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. Note that [`Uuid::encode_buffer`] isn't large enough.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Proquint, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("7f000001-0000-0000-0000-000000000000")?;
+    ///
+    /// assert_eq!(
+    ///     "lusab-babad-babab-babab-babab-babab-babab-babab",
+    ///     uuid.proquint().encode(&mut [0; Proquint::LENGTH]),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let buf = &mut buffer[..Self::LENGTH];
+        let buf: &mut [u8; Proquint::LENGTH] = buf.try_into().unwrap();
+        *buf = format_proquint(&self.0);
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Proquint`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+const fn format_proquint(uuid: &Uuid) -> [u8; Proquint::LENGTH] {
+    let bytes = uuid.as_bytes();
+    let mut dst = [b'-'; Proquint::LENGTH];
+
+    // Each word is `con vow con vow con`, taking 4, 2, 4, 2, and 4 bits
+    let mut i = 0;
+    while i < 8 {
+        let word = (bytes[i * 2] as u16) << 8 | bytes[i * 2 + 1] as u16;
+        let offset = i * 6;
+
+        dst[offset] = CONSONANTS[(word >> 12) as usize & 0xf];
+        dst[offset + 1] = VOWELS[(word >> 10) as usize & 0x3];
+        dst[offset + 2] = CONSONANTS[(word >> 6) as usize & 0xf];
+        dst[offset + 3] = VOWELS[(word >> 4) as usize & 0x3];
+        dst[offset + 4] = CONSONANTS[word as usize & 0xf];
+
+        i += 1;
+    }
+
+    dst
+}
+
+enum Invalid {
+    Length,
+    Char(usize),
+}
+
+const fn decode(input: &[u8]) -> Result<Uuid, Invalid> {
+    if input.len() != Proquint::LENGTH {
+        return Err(Invalid::Length);
+    }
+
+    let mut bytes = [0; 16];
+
+    let mut i = 0;
+    while i < 8 {
+        let offset = i * 6;
+
+        if i > 0 && input[offset - 1] != b'-' {
+            return Err(Invalid::Char(offset - 1));
+        }
+
+        let mut word = 0u16;
+
+        let mut j = 0;
+        while j < 5 {
+            // Consonants and vowels alternate, starting with a consonant
+            let (table, bits) = if j % 2 == 0 {
+                (&DECODE_CONSONANT, 4)
+            } else {
+                (&DECODE_VOWEL, 2)
+            };
+
+            let value = table[input[offset + j] as usize];
+
+            if value == 0xff {
+                return Err(Invalid::Char(offset + j));
+            }
+
+            word = (word << bits) | value as u16;
+            j += 1;
+        }
+
+        bytes[i * 2] = (word >> 8) as u8;
+        bytes[i * 2 + 1] = word as u8;
+
+        i += 1;
+    }
+
+    Ok(Uuid::from_bytes(bytes))
+}
+
+impl FromStr for Proquint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Proquint::parse_str(s)
+    }
+}
+
+impl fmt::Display for Proquint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl_fmt_from! {
+    Proquint<>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    const VALID: &[(&str, &str)] = &[
+        (
+            "00000000-0000-0000-0000-000000000000",
+            "babab-babab-babab-babab-babab-babab-babab-babab",
+        ),
+        (
+            "7f000001-0000-0000-0000-000000000000",
+            "lusab-babad-babab-babab-babab-babab-babab-babab",
+        ),
+        (
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "kizoj-jadah-dafud-hanoz-nanal-rotom-buniz-vagam",
+        ),
+        (
+            "01890a5d-ac96-774b-bcce-b302099a8057",
+            "bakan-bonit-pufik-litar-rugav-rasaf-bokip-madil",
+        ),
+        (
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "zuzuz-zuzuz-zuzuz-zuzuz-zuzuz-zuzuz-zuzuz-zuzuz",
+        ),
+    ];
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_roundtrip() {
+        for (uuid, encoded) in VALID {
+            let uuid = Uuid::parse_str(uuid).unwrap();
+
+            assert_eq!(*encoded, uuid.proquint().to_string());
+            assert_eq!(*encoded, uuid.as_proquint().to_string());
+
+            assert_eq!(uuid, Proquint::parse_str(encoded).unwrap().into_uuid());
+            assert_eq!(
+                uuid,
+                Proquint::parse_str(&encoded.to_ascii_uppercase())
+                    .unwrap()
+                    .into_uuid()
+            );
+            assert_eq!(uuid, Proquint::try_parse(encoded).unwrap().into_uuid());
+            assert_eq!(uuid, encoded.parse::<Proquint>().unwrap().into_uuid());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_invalid() {
+        for (input, err) in [
+            ("", ErrorKind::ProquintLength { len: 0 }),
+            ("lusab-babad", ErrorKind::ProquintLength { len: 11 }),
+            (
+                "kizoj-jadah-dafud-hanoz-nanal-rotom-buniz-vagam-",
+                ErrorKind::ProquintLength { len: 48 },
+            ),
+            (
+                "kizoj jadah-dafud-hanoz-nanal-rotom-buniz-vagam",
+                ErrorKind::ParseChar {
+                    character: ' ',
+                    index: 6,
+                },
+            ),
+            // A vowel where a consonant should be
+            (
+                "kizoj-jadah-dafud-hanoz-nanal-rotom-buniz-vagaa",
+                ErrorKind::ParseChar {
+                    character: 'a',
+                    index: 47,
+                },
+            ),
+            // A consonant where a vowel should be
+            (
+                "kbzoj-jadah-dafud-hanoz-nanal-rotom-buniz-vagam",
+                ErrorKind::ParseChar {
+                    character: 'b',
+                    index: 2,
+                },
+            ),
+            // `c` isn't used
+            (
+                "kizoj-jadah-dafud-hanoz-nanal-rotom-cuniz-vagam",
+                ErrorKind::ParseChar {
+                    character: 'c',
+                    index: 37,
+                },
+            ),
+        ] {
+            assert_eq!(
+                Error(err),
+                Proquint::parse_str(input).unwrap_err(),
+                "{input}"
+            );
+        }
+
+        assert_eq!(
+            Error(ErrorKind::ParseOther),
+            Proquint::try_parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap_err()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_small_buffer() {
+        Uuid::nil().proquint().encode(&mut [0; 46]);
+    }
+}
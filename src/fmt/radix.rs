@@ -0,0 +1,660 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{
+    error::*,
+    std::{borrow::Borrow, fmt, hash, str},
+    Uuid,
+};
+
+/// The symbols used to encode a [`Uuid`] with [`Radix`].
+///
+/// An alphabet is made up of between 2 and 94 distinct printable ASCII characters.
+/// The first character is the zero digit, and each character after it is worth one more.
+/// If the characters are in ASCII order then padded strings sort in the same order as
+/// the UUIDs they encode.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::fmt::Alphabet;
+/// # fn main() -> Result<(), uuid::Error> {
+/// let hex = Alphabet::new("0123456789abcdef")?;
+///
+/// assert_eq!(16, hex.radix());
+/// assert_eq!(32, hex.width());
+///
+/// assert!(Alphabet::new("0120").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Alphabet {
+    symbols: [u8; 128],
+    // Maps an ASCII byte back to its digit, or `0xff` if it's not in the alphabet
+    decode: [u8; 128],
+    radix: u8,
+    width: u8,
+}
+
+impl Alphabet {
+    /// The Bitcoin base58 alphabet.
+    ///
+    /// It leaves out `0`, `O`, `I`, and `l`, so strings can be copied by hand
+    /// without confusing similar looking characters.
+    pub const BASE58: Alphabet = Alphabet::expect(
+        "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+        false,
+    );
+
+    /// The digits, followed by the upper-case and lower-case letters.
+    ///
+    /// These are all the characters that are safe to use in URLs without escaping.
+    pub const BASE62: Alphabet = Alphabet::expect(
+        "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+        false,
+    );
+
+    /// The digits, followed by the lower-case letters.
+    ///
+    /// Decoding is case-insensitive.
+    pub const BASE36: Alphabet = Alphabet::expect("0123456789abcdefghijklmnopqrstuvwxyz", true);
+
+    /// Create an alphabet from the given symbols.
+    ///
+    /// The symbols must be between 2 and 94 distinct printable ASCII characters.
+    /// Decoding is case-sensitive.
+    pub const fn new(symbols: &str) -> Result<Self, Error> {
+        match Alphabet::build(symbols.as_bytes(), false) {
+            Some(alphabet) => Ok(alphabet),
+            None => Err(Error(ErrorKind::RadixAlphabet)),
+        }
+    }
+
+    /// Create an alphabet from the given symbols that decodes case-insensitively.
+    ///
+    /// The symbols must be between 2 and 94 printable ASCII characters that are
+    /// distinct when case is ignored. Strings are encoded with the symbols as given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::fmt::{Alphabet, Radix};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let alphabet = Alphabet::new_case_insensitive("0123456789abcdef")?;
+    ///
+    /// assert_eq!(
+    ///     Radix::parse_str("FF", &alphabet)?,
+    ///     Radix::parse_str("ff", &alphabet)?,
+    /// );
+    ///
+    /// assert!(Alphabet::new_case_insensitive("abcABC").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn new_case_insensitive(symbols: &str) -> Result<Self, Error> {
+        match Alphabet::build(symbols.as_bytes(), true) {
+            Some(alphabet) => Ok(alphabet),
+            None => Err(Error(ErrorKind::RadixAlphabet)),
+        }
+    }
+
+    /// The symbols of the alphabet.
+    pub fn symbols(&self) -> &str {
+        // SAFETY: The symbols are ASCII encoded
+        unsafe { str::from_utf8_unchecked(&self.symbols[..self.radix as usize]) }
+    }
+
+    /// The number of symbols in the alphabet.
+    pub const fn radix(&self) -> usize {
+        self.radix as usize
+    }
+
+    /// The number of characters needed to encode any [`Uuid`] with this alphabet.
+    ///
+    /// This is the length of a padded [`Radix`] string.
+    pub const fn width(&self) -> usize {
+        self.width as usize
+    }
+
+    const fn expect(symbols: &str, ignore_case: bool) -> Self {
+        match Alphabet::build(symbols.as_bytes(), ignore_case) {
+            Some(alphabet) => alphabet,
+            None => panic!("invalid radix alphabet"),
+        }
+    }
+
+    const fn build(input: &[u8], ignore_case: bool) -> Option<Self> {
+        if input.len() < 2 || input.len() > 128 {
+            return None;
+        }
+
+        let mut symbols = [0; 128];
+        let mut decode = [0xff; 128];
+
+        let mut i = 0;
+        while i < input.len() {
+            let symbol = input[i];
+
+            if !symbol.is_ascii_graphic() {
+                return None;
+            }
+
+            let (a, b) = if ignore_case {
+                (
+                    symbol.to_ascii_lowercase() as usize,
+                    symbol.to_ascii_uppercase() as usize,
+                )
+            } else {
+                (symbol as usize, symbol as usize)
+            };
+
+            if decode[a] != 0xff || decode[b] != 0xff {
+                return None;
+            }
+
+            symbols[i] = symbol;
+            decode[a] = i as u8;
+            decode[b] = i as u8;
+
+            i += 1;
+        }
+
+        // Count the digits needed for the largest UUID
+        let radix = input.len() as u128;
+        let mut remaining = u128::MAX;
+        let mut width = 0;
+        while remaining > 0 {
+            remaining /= radix;
+            width += 1;
+        }
+
+        Some(Alphabet {
+            symbols,
+            decode,
+            radix: input.len() as u8,
+            width,
+        })
+    }
+}
+
+impl fmt::Debug for Alphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Alphabet").field(&self.symbols()).finish()
+    }
+}
+
+impl PartialEq for Alphabet {
+    fn eq(&self, other: &Self) -> bool {
+        self.symbols == other.symbols && self.decode == other.decode
+    }
+}
+
+impl Eq for Alphabet {}
+
+impl hash::Hash for Alphabet {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.symbols.hash(state);
+        self.decode.hash(state);
+    }
+}
+
+/// Format a [`Uuid`] as a number in an arbitrary [`Alphabet`], like
+/// `Dq7QdGPZBdz9vwjm3jLQSB` in base58.
+///
+/// The UUID is encoded as a 128-bit big-endian number, like [`Uuid::as_u128`],
+/// using the symbols of the alphabet as digits. By default leading zeros are
+/// left out, so the length of the string depends on the UUID. A [padded](Radix::padded)
+/// string is always [`Alphabet::width`] characters long, so padded strings sort
+/// in the same order as the UUIDs they encode when the alphabet is in ASCII order.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::{Alphabet, Radix}, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057")?;
+///
+/// assert_eq!("BzmjTFLHWXwiSK4y3H5iW", uuid.radix(&Alphabet::BASE58).to_string());
+/// assert_eq!("2tcRIyrxLXTR81B3dqdOx", uuid.radix(&Alphabet::BASE62).to_string());
+/// assert_eq!("039srkj01e44uynszf2f2njh3", uuid.radix(&Alphabet::BASE36).padded().to_string());
+///
+/// let parsed = Radix::parse_str("BzmjTFLHWXwiSK4y3H5iW", &Alphabet::BASE58)?;
+///
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Radix<'alphabet> {
+    uuid: Uuid,
+    alphabet: &'alphabet Alphabet,
+    padded: bool,
+}
+
+impl Uuid {
+    /// Get a [`Radix`] formatter using the given [`Alphabet`].
+    #[inline]
+    pub const fn radix(self, alphabet: &Alphabet) -> Radix<'_> {
+        Radix::from_uuid(self, alphabet)
+    }
+}
+
+impl<'alphabet> Radix<'alphabet> {
+    /// The length of the longest radix string, which is the width of a base 2 alphabet.
+    pub const MAX_LENGTH: usize = 128;
+
+    /// Creates a [`Radix`] from a [`Uuid`] and an [`Alphabet`].
+    pub const fn from_uuid(uuid: Uuid, alphabet: &'alphabet Alphabet) -> Self {
+        Radix {
+            uuid,
+            alphabet,
+            padded: false,
+        }
+    }
+
+    /// Pad the string with leading zero digits to the [`Alphabet::width`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Alphabet, Uuid};
+    /// assert_eq!("1", Uuid::nil().radix(&Alphabet::BASE58).to_string());
+    /// assert_eq!(
+    ///     "1111111111111111111111",
+    ///     Uuid::nil().radix(&Alphabet::BASE58).padded().to_string(),
+    /// );
+    /// ```
+    pub const fn padded(mut self) -> Self {
+        self.padded = true;
+        self
+    }
+
+    /// Parses a radix string using the given [`Alphabet`].
+    ///
+    /// The input must be between 1 and [`Alphabet::width`] characters of the alphabet,
+    /// and encode a value that fits in 128 bits. Leading zero digits are allowed. The
+    /// result is [padded](Radix::padded) if the input was exactly `width` characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::{Alphabet, Radix}, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// assert_eq!(
+    ///     Uuid::max(),
+    ///     Radix::parse_str("f5lxx1zz5pnorynqglhzmsp33", &Alphabet::BASE36)?.into_uuid(),
+    /// );
+    ///
+    /// // The value is larger than 128 bits
+    /// assert!(Radix::parse_str("f5lxx1zz5pnorynqglhzmsp34", &Alphabet::BASE36).is_err());
+    /// // `0` isn't in the base58 alphabet
+    /// assert!(Radix::parse_str("0", &Alphabet::BASE58).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(input: &str, alphabet: &'alphabet Alphabet) -> Result<Self, Error> {
+        match decode(input.as_bytes(), alphabet) {
+            Ok(radix) => Ok(radix),
+            Err(Invalid::Length) => Err(Error(ErrorKind::RadixLength {
+                len: input.len(),
+                max: alphabet.width(),
+            })),
+            Err(Invalid::Char(index)) => Err(Error(ErrorKind::ParseChar {
+                // Every byte before the index is ASCII, so it's at a character boundary
+                character: input[index..].chars().next().unwrap(),
+                index: index + 1,
+            })),
+            Err(Invalid::Overflow) => Err(Error(ErrorKind::RadixOverflow)),
+        }
+    }
+
+    /// Parses a radix string using the given [`Alphabet`] in a `const` context.
+    ///
+    /// This method accepts the same input as [`Radix::parse_str`], but
+    /// won't generate very useful error messages if parsing fails.
+    pub const fn try_parse(input: &str, alphabet: &'alphabet Alphabet) -> Result<Self, Error> {
+        match decode(input.as_bytes(), alphabet) {
+            Ok(radix) => Ok(radix),
+            Err(_) => Err(Error(ErrorKind::ParseOther)),
+        }
+    }
+
+    /// Writes the [`Uuid`] as a radix string to `buffer`, and returns the subslice
+    /// of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`Alphabet::width`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer for alphabets of 8 or more symbols,
+    /// and [`MAX_LENGTH`] is enough for any alphabet.
+    ///
+    /// [`MAX_LENGTH`]: #associatedconstant.MAX_LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Alphabet, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(
+    ///     "Dq7QdGPZBdz9vwjm3jLQSB",
+    ///     uuid.radix(&Alphabet::BASE58).encode(&mut Uuid::encode_buffer()),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let alphabet = self.alphabet;
+        let radix = alphabet.radix as u128;
+        let width = alphabet.width();
+
+        let buf = &mut buffer[..width];
+
+        // Write digits from the least significant, at the end of the buffer
+        let mut value = self.uuid.as_u128();
+        let mut start = width;
+        while start > 0 && (value > 0 || start == width || self.padded) {
+            start -= 1;
+            buf[start] = alphabet.symbols[(value % radix) as usize];
+            value /= radix;
+        }
+
+        buf.copy_within(start.., 0);
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(&mut buf[..width - start]) }
+    }
+
+    /// The [`Alphabet`] used to encode the [`Uuid`].
+    pub const fn alphabet(&self) -> &'alphabet Alphabet {
+        self.alphabet
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Consumes the [`Radix`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.uuid
+    }
+}
+
+enum Invalid {
+    Length,
+    Char(usize),
+    Overflow,
+}
+
+const fn decode<'a>(input: &[u8], alphabet: &'a Alphabet) -> Result<Radix<'a>, Invalid> {
+    if input.is_empty() || input.len() > alphabet.width() {
+        return Err(Invalid::Length);
+    }
+
+    let radix = alphabet.radix as u128;
+    let mut value = 0u128;
+
+    let mut i = 0;
+    while i < input.len() {
+        let digit = if input[i] < 128 {
+            alphabet.decode[input[i] as usize]
+        } else {
+            0xff
+        };
+
+        if digit == 0xff {
+            return Err(Invalid::Char(i));
+        }
+
+        value = match value.checked_mul(radix) {
+            Some(value) => match value.checked_add(digit as u128) {
+                Some(value) => value,
+                None => return Err(Invalid::Overflow),
+            },
+            None => return Err(Invalid::Overflow),
+        };
+
+        i += 1;
+    }
+
+    Ok(Radix {
+        uuid: Uuid::from_u128(value),
+        alphabet,
+        padded: input.len() == alphabet.width(),
+    })
+}
+
+impl fmt::Display for Radix<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::MAX_LENGTH]))
+    }
+}
+
+impl From<Radix<'_>> for Uuid {
+    #[inline]
+    fn from(f: Radix<'_>) -> Self {
+        f.into_uuid()
+    }
+}
+
+impl AsRef<Uuid> for Radix<'_> {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+impl Borrow<Uuid> for Radix<'_> {
+    #[inline]
+    fn borrow(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    // The UUID, followed by its base58, base62, and padded base36 strings
+    const VALID: &[(&str, &str, &str, &str)] = &[
+        (
+            "00000000-0000-0000-0000-000000000000",
+            "1",
+            "0",
+            "0000000000000000000000000",
+        ),
+        (
+            "00000000-0000-0000-0000-00000000003a",
+            "21",
+            "w",
+            "000000000000000000000001m",
+        ),
+        (
+            "01890a5d-ac96-774b-bcce-b302099a8057",
+            "BzmjTFLHWXwiSK4y3H5iW",
+            "2tcRIyrxLXTR81B3dqdOx",
+            "039srkj01e44uynszf2f2njh3",
+        ),
+        (
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "Dq7QdGPZBdz9vwjm3jLQSB",
+            "3A30O3qtpTRBe7GtXy50u0",
+            "65fjgt56no7e0j1xr28lgpsjs",
+        ),
+        (
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "YcVfxkQb6JRzqk5kF2tNLv",
+            "7n42DGM5Tflk9n8mt7Fhc7",
+            "f5lxx1zz5pnorynqglhzmsp33",
+        ),
+    ];
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_roundtrip() {
+        for (uuid, base58, base62, base36) in VALID {
+            let uuid = Uuid::parse_str(uuid).unwrap();
+
+            for (alphabet, encoded) in [(&Alphabet::BASE58, *base58), (&Alphabet::BASE62, *base62)]
+            {
+                assert_eq!(encoded, uuid.radix(alphabet).to_string());
+                assert_eq!(
+                    uuid,
+                    Radix::parse_str(encoded, alphabet).unwrap().into_uuid()
+                );
+                assert_eq!(
+                    uuid,
+                    Radix::try_parse(encoded, alphabet).unwrap().into_uuid()
+                );
+            }
+
+            assert_eq!(*base36, uuid.radix(&Alphabet::BASE36).padded().to_string());
+
+            let parsed = Radix::parse_str(base36, &Alphabet::BASE36).unwrap();
+
+            assert_eq!(uuid.radix(&Alphabet::BASE36).padded(), parsed);
+            assert_eq!(
+                parsed,
+                Radix::parse_str(&base36.to_ascii_uppercase(), &Alphabet::BASE36).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_padded_sorts() {
+        let mut uuids = VALID
+            .iter()
+            .map(|(uuid, ..)| Uuid::parse_str(uuid).unwrap())
+            .collect::<crate::std::vec::Vec<_>>();
+
+        for alphabet in [&Alphabet::BASE58, &Alphabet::BASE62, &Alphabet::BASE36] {
+            uuids.sort_by_key(|uuid| uuid.radix(alphabet).padded().to_string());
+
+            assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+
+            for uuid in &uuids {
+                let padded = uuid.radix(alphabet).padded().to_string();
+
+                assert_eq!(alphabet.width(), padded.len());
+                assert_eq!(
+                    *uuid,
+                    Radix::parse_str(&padded, alphabet).unwrap().into_uuid()
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_alphabets() {
+        assert_eq!(
+            (58, 22),
+            (Alphabet::BASE58.radix(), Alphabet::BASE58.width())
+        );
+        assert_eq!(
+            (62, 22),
+            (Alphabet::BASE62.radix(), Alphabet::BASE62.width())
+        );
+        assert_eq!(
+            (36, 25),
+            (Alphabet::BASE36.radix(), Alphabet::BASE36.width())
+        );
+
+        let binary = Alphabet::new("01").unwrap();
+
+        assert_eq!(Radix::MAX_LENGTH, binary.width());
+        assert_eq!("1".repeat(128), Uuid::max().radix(&binary).to_string());
+
+        for invalid in ["", "0", "00", "0 1", "01\n", "0ü"] {
+            assert_eq!(
+                Error(ErrorKind::RadixAlphabet),
+                Alphabet::new(invalid).unwrap_err(),
+                "{invalid:?}"
+            );
+        }
+
+        assert!(Alphabet::new("aA").is_ok());
+        assert!(Alphabet::new_case_insensitive("aA").is_err());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_invalid() {
+        let base58 = &Alphabet::BASE58;
+
+        assert_eq!(
+            Error(ErrorKind::RadixLength { len: 0, max: 22 }),
+            Radix::parse_str("", base58).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::RadixLength { len: 23, max: 22 }),
+            Radix::parse_str("11111111111111111111111", base58).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: 'l',
+                index: 3
+            }),
+            Radix::parse_str("Dql7QdGPZBdz9vwjm3jLQS", base58).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: 'ü',
+                index: 2
+            }),
+            Radix::parse_str("3ü", base58).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::RadixOverflow),
+            Radix::parse_str("zzzzzzzzzzzzzzzzzzzzzz", base58).unwrap_err()
+        );
+
+        // Base58 is case-sensitive
+        assert_ne!(
+            Radix::parse_str("Dq7QdGPZBdz9vwjm3jLQSB", base58).unwrap(),
+            Radix::parse_str("Dq7qdGPZBdz9vwjm3jLQSB", base58).unwrap()
+        );
+
+        assert_eq!(
+            Error(ErrorKind::ParseOther),
+            Radix::try_parse("", base58).unwrap_err()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_small_buffer() {
+        Uuid::max().radix(&Alphabet::BASE36).encode(&mut [0; 24]);
+    }
+}
@@ -0,0 +1,769 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::str::FromStr;
+
+use crate::{
+    error::*,
+    parser::HEX_TABLE,
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+/// Format a [`Uuid`] as a C or IDL struct initializer, like
+/// `{0x6b29fc40, 0xca47, 0x1067, {0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xe1}}`.
+///
+/// This is the layout of the Windows `GUID` struct, which is also used by
+/// `DEFINE_GUID`. The fields come from [`Uuid::as_fields`], so the first three
+/// are written as numbers rather than bytes, and compilers will put them in the
+/// right byte order for the target.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::CStruct, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("6b29fc40-ca47-1067-b31d-00dd010662e1")?;
+///
+/// assert_eq!(
+///     "{0x6b29fc40, 0xca47, 0x1067, {0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xe1}}",
+///     uuid.c_struct().to_string(),
+/// );
+///
+/// let parsed: CStruct = "{0x6B29FC40,0xCA47,0x1067,{0xB3,0x1D,0x0,0xDD,0x1,0x6,0x62,0xE1}}".parse()?;
+///
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    all(uuid_unstable, feature = "zerocopy"),
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct CStruct(Uuid);
+
+/// Format a [`Uuid`] as a C# `Guid` constructor, like
+/// `new Guid("6b29fc40-ca47-1067-b31d-00dd010662e1")`.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::Uuid;
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("6b29fc40-ca47-1067-b31d-00dd010662e1")?;
+///
+/// assert_eq!(
+///     "new Guid(\"6b29fc40-ca47-1067-b31d-00dd010662e1\")",
+///     uuid.c_sharp().to_string(),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    all(uuid_unstable, feature = "zerocopy"),
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct CSharp(Uuid);
+
+/// Format a [`Uuid`] as a Rust expression, like
+/// `Uuid::from_u128(0x6b29fc40_ca47_1067_b31d_00dd010662e1)`.
+///
+/// The digits of the integer literal are grouped like a hyphenated UUID, so
+/// the generated code is easy to compare against the string form.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::Uuid;
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("6b29fc40-ca47-1067-b31d-00dd010662e1")?;
+///
+/// assert_eq!(
+///     "Uuid::from_u128(0x6b29fc40_ca47_1067_b31d_00dd010662e1)",
+///     uuid.rust_literal().to_string(),
+/// );
+///
+/// assert_eq!(uuid, Uuid::from_u128(0x6b29fc40_ca47_1067_b31d_00dd010662e1));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    all(uuid_unstable, feature = "zerocopy"),
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct RustLiteral(Uuid);
+
+impl Uuid {
+    /// Get a [`CStruct`] formatter.
+    #[inline]
+    pub const fn c_struct(self) -> CStruct {
+        CStruct(self)
+    }
+
+    /// Get a borrowed [`CStruct`] formatter.
+    #[inline]
+    pub fn as_c_struct(&self) -> &CStruct {
+        unsafe_transmute_ref!(self)
+    }
+
+    /// Get a [`CSharp`] formatter.
+    #[inline]
+    pub const fn c_sharp(self) -> CSharp {
+        CSharp(self)
+    }
+
+    /// Get a borrowed [`CSharp`] formatter.
+    #[inline]
+    pub fn as_c_sharp(&self) -> &CSharp {
+        unsafe_transmute_ref!(self)
+    }
+
+    /// Get a [`RustLiteral`] formatter.
+    #[inline]
+    pub const fn rust_literal(self) -> RustLiteral {
+        RustLiteral(self)
+    }
+
+    /// Get a borrowed [`RustLiteral`] formatter.
+    #[inline]
+    pub fn as_rust_literal(&self) -> &RustLiteral {
+        unsafe_transmute_ref!(self)
+    }
+}
+
+// The shape of a struct initializer, where `n` is a hex number
+const PATTERN: &[u8] = b"{n,n,n,{n,n,n,n,n,n,n,n}}";
+
+impl CStruct {
+    /// The length of a struct initializer [`Uuid`] string.
+    pub const LENGTH: usize = 78;
+
+    /// Creates a [`CStruct`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        CStruct(uuid)
+    }
+
+    /// Parses a C or IDL struct initializer.
+    ///
+    /// The input is more flexible than the output of [`CStruct::encode`], so
+    /// initializers can be copied from existing source code:
+    ///
+    /// - Any ASCII whitespace is allowed between tokens.
+    /// - Hex digits and the `0x` prefix can be in either case.
+    /// - Leading zeros can be left out, like `0x0` instead of `0x00`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::CStruct, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let parsed = CStruct::parse_str(
+    ///     "{ 0x0, 0x0, 0x0, { 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0 } }",
+    /// )?;
+    ///
+    /// assert_eq!(Uuid::nil(), parsed.into_uuid());
+    ///
+    /// // The first field only has room for 8 digits
+    /// assert!(CStruct::parse_str("{0x100000000, 0x0, 0x0, {0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0}}").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(CStruct(uuid)),
+            Err(Invalid::Char(index)) => Err(Error(ErrorKind::ParseChar {
+                // Every byte before the index is ASCII, so it's at a character boundary
+                character: input[index..].chars().next().unwrap(),
+                index: index + 1,
+            })),
+            Err(Invalid::End) => Err(Error(ErrorKind::CStructIncomplete)),
+        }
+    }
+
+    /// Parses a C or IDL struct initializer in a `const` context.
+    ///
+    /// This method accepts the same input as [`CStruct::parse_str`], but
+    /// won't generate very useful error messages if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::fmt::CStruct;
+    /// const IID: CStruct = match CStruct::try_parse(
+    ///     "{0x00000000, 0x0000, 0x0000, {0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46}}",
+    /// ) {
+    ///     Ok(iid) => iid,
+    ///     Err(_) => panic!("invalid initializer"),
+    /// };
+    ///
+    /// assert_eq!("00000000-0000-0000-c000-000000000046", IID.as_uuid().to_string());
+    /// ```
+    pub const fn try_parse(input: &str) -> Result<Self, Error> {
+        match decode(input.as_bytes()) {
+            Ok(uuid) => Ok(CStruct(uuid)),
+            Err(_) => Err(Error(ErrorKind::ParseOther)),
+        }
+    }
+
+    /// Writes the [`Uuid`] as a struct initializer to `buffer`, and returns the
+    /// subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. This is longer than [`Uuid::encode_buffer`], so use an array
+    /// of [`LENGTH`] bytes as the buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::CStruct, Uuid};
+    /// assert_eq!(
+    ///     "{0xffffffff, 0xffff, 0xffff, {0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff}}",
+    ///     Uuid::max().c_struct().encode(&mut [0; CStruct::LENGTH]),
+    /// );
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let buf = &mut buffer[..Self::LENGTH];
+        let (d1, d2, d3, d4) = self.0.as_fields();
+
+        let mut i = 0;
+        let mut write = |part: &[u8]| {
+            buf[i..i + part.len()].copy_from_slice(part);
+            i += part.len();
+        };
+
+        write(b"{");
+        write(&format_field(d1, 8));
+        write(b", ");
+        write(&format_field(d2 as u32, 4)[..6]);
+        write(b", ");
+        write(&format_field(d3 as u32, 4)[..6]);
+        write(b", {");
+        for (j, byte) in d4.iter().enumerate() {
+            if j > 0 {
+                write(b", ");
+            }
+
+            write(&format_field(*byte as u32, 2)[..4]);
+        }
+        write(b"}}");
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`CStruct`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+// Formats `value` as `0x` followed by `digits` lower-case hex digits, at the start
+// of an array that fits the largest field
+const fn format_field(value: u32, digits: usize) -> [u8; 10] {
+    let mut dst = [0; 10];
+    dst[0] = b'0';
+    dst[1] = b'x';
+
+    let mut i = 0;
+    while i < digits {
+        let shift = (digits - 1 - i) * 4;
+        dst[2 + i] = super::LOWER[((value >> shift) & 0xf) as usize];
+        i += 1;
+    }
+
+    dst
+}
+
+enum Invalid {
+    Char(usize),
+    End,
+}
+
+const fn decode(input: &[u8]) -> Result<Uuid, Invalid> {
+    // The values of the numbers in the pattern, in order
+    let mut fields = [0u32; 11];
+    let mut field = 0;
+
+    let mut i = 0;
+    let mut p = 0;
+    while p < PATTERN.len() {
+        while i < input.len() && input[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        if i == input.len() {
+            return Err(Invalid::End);
+        }
+
+        if PATTERN[p] != b'n' {
+            if input[i] != PATTERN[p] {
+                return Err(Invalid::Char(i));
+            }
+
+            i += 1;
+            p += 1;
+            continue;
+        }
+
+        if input[i] != b'0' {
+            return Err(Invalid::Char(i));
+        }
+        if i + 1 == input.len() {
+            return Err(Invalid::End);
+        }
+        if input[i + 1] != b'x' && input[i + 1] != b'X' {
+            return Err(Invalid::Char(i + 1));
+        }
+        i += 2;
+
+        let width = match field {
+            0 => 8,
+            1 | 2 => 4,
+            _ => 2,
+        };
+
+        let start = i;
+        let mut value = 0u32;
+        while i < input.len() && HEX_TABLE[input[i] as usize] != 0xff {
+            if i - start == width {
+                return Err(Invalid::Char(i));
+            }
+
+            value = (value << 4) | HEX_TABLE[input[i] as usize] as u32;
+            i += 1;
+        }
+
+        if i == start {
+            return if i == input.len() {
+                Err(Invalid::End)
+            } else {
+                Err(Invalid::Char(i))
+            };
+        }
+
+        fields[field] = value;
+        field += 1;
+        p += 1;
+    }
+
+    while i < input.len() {
+        if !input[i].is_ascii_whitespace() {
+            return Err(Invalid::Char(i));
+        }
+
+        i += 1;
+    }
+
+    let d4 = [
+        fields[3] as u8,
+        fields[4] as u8,
+        fields[5] as u8,
+        fields[6] as u8,
+        fields[7] as u8,
+        fields[8] as u8,
+        fields[9] as u8,
+        fields[10] as u8,
+    ];
+
+    Ok(Uuid::from_fields(
+        fields[0],
+        fields[1] as u16,
+        fields[2] as u16,
+        &d4,
+    ))
+}
+
+impl CSharp {
+    /// The length of a C# `Guid` constructor [`Uuid`] string.
+    pub const LENGTH: usize = 48;
+
+    /// Creates a [`CSharp`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        CSharp(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a C# `Guid` constructor to `buffer`, and returns
+    /// the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. This is longer than [`Uuid::encode_buffer`], so use an array
+    /// of [`LENGTH`] bytes as the buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::CSharp, Uuid};
+    /// assert_eq!(
+    ///     "new Guid(\"00000000-0000-0000-0000-000000000000\")",
+    ///     Uuid::nil().c_sharp().encode(&mut [0; CSharp::LENGTH]),
+    /// );
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let buf: &mut [u8; Self::LENGTH] = (&mut buffer[..Self::LENGTH]).try_into().unwrap();
+
+        buf[..10].copy_from_slice(b"new Guid(\"");
+        buf[10..46].copy_from_slice(&super::format_hyphenated(self.0.as_bytes(), false));
+        buf[46..].copy_from_slice(b"\")");
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`CSharp`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl RustLiteral {
+    /// The length of a Rust expression [`Uuid`] string.
+    pub const LENGTH: usize = 55;
+
+    /// Creates a [`RustLiteral`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        RustLiteral(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a Rust expression to `buffer`, and returns the
+    /// subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. This is longer than [`Uuid::encode_buffer`], so use an array
+    /// of [`LENGTH`] bytes as the buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::RustLiteral, Uuid};
+    /// assert_eq!(
+    ///     "Uuid::from_u128(0xffffffff_ffff_ffff_ffff_ffffffffffff)",
+    ///     Uuid::max().rust_literal().encode(&mut [0; RustLiteral::LENGTH]),
+    /// );
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let buf: &mut [u8; Self::LENGTH] = (&mut buffer[..Self::LENGTH]).try_into().unwrap();
+
+        let mut digits = super::format_hyphenated(self.0.as_bytes(), false);
+        for digit in &mut digits {
+            if *digit == b'-' {
+                *digit = b'_';
+            }
+        }
+
+        buf[..18].copy_from_slice(b"Uuid::from_u128(0x");
+        buf[18..54].copy_from_slice(&digits);
+        buf[54] = b')';
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`RustLiteral`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl FromStr for CStruct {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CStruct::parse_str(s)
+    }
+}
+
+impl fmt::Display for CStruct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl_fmt_from! {
+    CStruct<>
+}
+
+impl fmt::Display for CSharp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl_fmt_from! {
+    CSharp<>
+}
+
+impl fmt::Display for RustLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl_fmt_from! {
+    RustLiteral<>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    const UUID: &str = "6b29fc40-ca47-1067-b31d-00dd010662e1";
+
+    const C_STRUCT: &str =
+        "{0x6b29fc40, 0xca47, 0x1067, {0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xe1}}";
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_c_struct_roundtrip() {
+        let uuid = Uuid::parse_str(UUID).unwrap();
+
+        assert_eq!(CStruct::LENGTH, C_STRUCT.len());
+        assert_eq!(C_STRUCT, uuid.c_struct().to_string());
+        assert_eq!(C_STRUCT, uuid.as_c_struct().to_string());
+
+        assert_eq!(uuid, CStruct::parse_str(C_STRUCT).unwrap().into_uuid());
+        assert_eq!(uuid, CStruct::try_parse(C_STRUCT).unwrap().into_uuid());
+        assert_eq!(uuid, C_STRUCT.parse::<CStruct>().unwrap().into_uuid());
+
+        for uuid in [Uuid::nil(), Uuid::max()] {
+            let encoded = uuid.c_struct().to_string();
+
+            assert_eq!(uuid, CStruct::parse_str(&encoded).unwrap().into_uuid());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_c_struct_flexible() {
+        let uuid = Uuid::parse_str(UUID).unwrap();
+
+        for input in [
+            "{0x6b29fc40,0xca47,0x1067,{0xb3,0x1d,0x00,0xdd,0x01,0x06,0x62,0xe1}}",
+            "{0X6B29FC40, 0XCA47, 0X1067, {0XB3, 0X1D, 0X0, 0XDD, 0X1, 0X6, 0X62, 0XE1}}",
+            "  {\n    0x6b29fc40,\n    0xca47,\n    0x1067,\n    { 0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xe1 }\n}\n",
+        ] {
+            assert_eq!(uuid, CStruct::parse_str(input).unwrap().into_uuid());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_c_struct_fields() {
+        let uuid = CStruct::parse_str(
+            "{0x00000001, 0x0002, 0x0003, {0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b}}",
+        )
+        .unwrap()
+        .into_uuid();
+
+        assert_eq!((1, 2, 3, &[4, 5, 6, 7, 8, 9, 10, 11]), uuid.as_fields());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_c_struct_invalid() {
+        assert_eq!(
+            Error(ErrorKind::CStructIncomplete),
+            CStruct::parse_str("").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::CStructIncomplete),
+            CStruct::parse_str(&C_STRUCT[..C_STRUCT.len() - 1]).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::CStructIncomplete),
+            CStruct::parse_str("{0x").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: '6',
+                index: 1
+            }),
+            CStruct::parse_str(UUID).unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: '1',
+                index: 12
+            }),
+            CStruct::parse_str(
+                "{0x6b29fc401, 0xca47, 0x1067, {0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xe1}}"
+            )
+            .unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: ',',
+                index: 16
+            }),
+            CStruct::parse_str(
+                "{0x6b29fc40, 0x, 0x1067, {0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xe1}}"
+            )
+            .unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: '6',
+                index: 2
+            }),
+            CStruct::parse_str(
+                "{6b29fc40, 0xca47, 0x1067, {0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xe1}}"
+            )
+            .unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: ';',
+                index: 79
+            }),
+            CStruct::parse_str(
+                "{0x6b29fc40, 0xca47, 0x1067, {0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xe1}};"
+            )
+            .unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseOther),
+            CStruct::try_parse("{}").unwrap_err()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_c_sharp() {
+        let uuid = Uuid::parse_str(UUID).unwrap();
+
+        assert_eq!(
+            "new Guid(\"6b29fc40-ca47-1067-b31d-00dd010662e1\")",
+            uuid.c_sharp().to_string()
+        );
+        assert_eq!(
+            "new Guid(\"6b29fc40-ca47-1067-b31d-00dd010662e1\")",
+            uuid.as_c_sharp().to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_rust_literal() {
+        let uuid = Uuid::parse_str(UUID).unwrap();
+
+        assert_eq!(
+            "Uuid::from_u128(0x6b29fc40_ca47_1067_b31d_00dd010662e1)",
+            uuid.rust_literal().to_string()
+        );
+        assert_eq!(
+            "Uuid::from_u128(0x6b29fc40_ca47_1067_b31d_00dd010662e1)",
+            uuid.as_rust_literal().to_string()
+        );
+        assert_eq!(
+            uuid,
+            Uuid::from_u128(0x6b29fc40_ca47_1067_b31d_00dd010662e1)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_c_struct_encode_small_buffer() {
+        Uuid::max().c_struct().encode(&mut Uuid::encode_buffer());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_c_sharp_encode_small_buffer() {
+        Uuid::max().c_sharp().encode(&mut Uuid::encode_buffer());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rust_literal_encode_small_buffer() {
+        Uuid::max()
+            .rust_literal()
+            .encode(&mut Uuid::encode_buffer());
+    }
+}
@@ -0,0 +1,506 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::str::FromStr;
+
+use crate::{
+    error::*,
+    std::{fmt, str},
+    Uuid,
+};
+
+use super::base32::{self, Invalid};
+
+/// Format a [`Uuid`] as a [TypeID], like `user_01h455vb4pex5vsknk084sn02q`.
+///
+/// A TypeID is a type prefix followed by an underscore and the UUID
+/// encoded as 26 characters of lower-case Crockford base32. The prefix
+/// is up to 63 lower-case ASCII letters or underscores, and can't start
+/// or end with an underscore. If the prefix is empty then the underscore
+/// is omitted.
+///
+/// Any UUID can be encoded as a TypeID, but the spec recommends using
+/// version 7 UUIDs so that identifiers sort by their creation time.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::TypeId, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057")?;
+/// let id = TypeId::new("user", uuid)?;
+///
+/// assert_eq!("user_01h455vb4pex5vsknk084sn02q", id.to_string());
+///
+/// let parsed: TypeId = "user_01h455vb4pex5vsknk084sn02q".parse()?;
+///
+/// assert_eq!("user", parsed.prefix());
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///
+/// * [TypeID Specification](https://github.com/jetify-com/typeid/tree/main/spec)
+///
+/// [TypeID]: https://github.com/jetify-com/typeid
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TypeId {
+    // Unused bytes are always zero, so the derived impls only consider the prefix
+    prefix: [u8; TypeId::MAX_PREFIX_LENGTH],
+    prefix_len: u8,
+    uuid: Uuid,
+}
+
+impl TypeId {
+    /// The maximum length of a TypeID prefix.
+    pub const MAX_PREFIX_LENGTH: usize = 63;
+
+    /// The length of a TypeID suffix.
+    pub const SUFFIX_LENGTH: usize = 26;
+
+    /// The maximum length of a TypeID string, including its prefix and separator.
+    pub const MAX_LENGTH: usize = Self::MAX_PREFIX_LENGTH + 1 + Self::SUFFIX_LENGTH;
+
+    /// Creates a [`TypeId`] from a [`Uuid`] with an empty prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::TypeId, Uuid};
+    /// let id = TypeId::from_uuid(Uuid::nil());
+    ///
+    /// assert_eq!("00000000000000000000000000", id.to_string());
+    /// ```
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        TypeId {
+            prefix: [0; Self::MAX_PREFIX_LENGTH],
+            prefix_len: 0,
+            uuid,
+        }
+    }
+
+    /// Creates a [`TypeId`] from a type prefix and a [`Uuid`].
+    ///
+    /// This method will return an error if the prefix is longer than
+    /// 63 characters, contains anything besides lower-case ASCII letters
+    /// or underscores, or starts or ends with an underscore.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::TypeId, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057")?;
+    ///
+    /// assert_eq!(
+    ///     "user_account_01h455vb4pex5vsknk084sn02q",
+    ///     TypeId::new("user_account", uuid)?.to_string(),
+    /// );
+    ///
+    /// assert!(TypeId::new("User", uuid).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(prefix: &str, uuid: Uuid) -> Result<Self, Error> {
+        validate_prefix(prefix.as_bytes())?;
+
+        let mut id = TypeId::from_uuid(uuid);
+        id.prefix[..prefix.len()].copy_from_slice(prefix.as_bytes());
+        id.prefix_len = prefix.len() as u8;
+
+        Ok(id)
+    }
+
+    /// Creates a [`TypeId`] with a new version 7 UUID using the current system time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::TypeId, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let id = TypeId::now_v7("user")?;
+    ///
+    /// assert_eq!("user", id.prefix());
+    /// assert_eq!(Some(Version::SortRand), id.as_uuid().get_version());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(feature = "v7", feature = "std"))]
+    pub fn now_v7(prefix: &str) -> Result<Self, Error> {
+        TypeId::new(prefix, Uuid::now_v7())
+    }
+
+    /// Parses a TypeID string.
+    ///
+    /// Any UUID is accepted in the suffix. To only accept version 7 UUIDs,
+    /// check the version of [`TypeId::as_uuid`] or convert it into a
+    /// [`UuidV7`](../struct.UuidV7.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::TypeId, UuidV7};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let id = TypeId::parse_str("user_01h455vb4pex5vsknk084sn02q")?;
+    ///
+    /// assert_eq!("user", id.prefix());
+    /// assert!(UuidV7::try_from(id.into_uuid()).is_ok());
+    ///
+    /// assert!(TypeId::parse_str("user_01H455VB4PEX5VSKNK084SN02Q").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        let (prefix, suffix, offset) = match input.rfind('_') {
+            // A separator is only allowed when there's a prefix
+            Some(0) => return Err(Error(ErrorKind::TypeIdPrefix)),
+            Some(separator) => (&input[..separator], &input[separator + 1..], separator + 1),
+            None => ("", input, 0),
+        };
+
+        let uuid = decode_suffix(suffix, offset)?;
+
+        TypeId::new(prefix, uuid)
+    }
+
+    /// Get the type prefix, which may be empty.
+    pub fn prefix(&self) -> &str {
+        // SAFETY: the prefix is validated to be ASCII when it's set
+        unsafe { str::from_utf8_unchecked(&self.prefix[..self.prefix_len as usize]) }
+    }
+
+    /// Writes the TypeID to `buffer`, and returns the subslice of the buffer
+    /// that contains the encoded string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough to hold the prefix, separator,
+    /// and suffix. A buffer of [`MAX_LENGTH`](#associatedconstant.MAX_LENGTH)
+    /// bytes is always large enough.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::TypeId, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let id = TypeId::new("user", Uuid::max())?;
+    ///
+    /// assert_eq!(
+    ///     "user_7zzzzzzzzzzzzzzzzzzzzzzzzz",
+    ///     id.encode(&mut [0; TypeId::MAX_LENGTH]),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let prefix = self.prefix().as_bytes();
+        let offset = if prefix.is_empty() {
+            0
+        } else {
+            prefix.len() + 1
+        };
+        let len = offset + Self::SUFFIX_LENGTH;
+
+        let buf = &mut buffer[..len];
+
+        if !prefix.is_empty() {
+            buf[..prefix.len()].copy_from_slice(prefix);
+            buf[prefix.len()] = b'_';
+        }
+
+        buf[offset..].copy_from_slice(&base32::format_base32(&self.uuid, false));
+
+        // SAFETY: the buffer only contains ASCII characters
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Consumes the [`TypeId`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.uuid
+    }
+}
+
+fn validate_prefix(prefix: &[u8]) -> Result<(), Error> {
+    match prefix {
+        [] => Ok(()),
+        [b'_', ..] | [.., b'_'] => Err(Error(ErrorKind::TypeIdPrefix)),
+        prefix
+            if prefix.len() <= TypeId::MAX_PREFIX_LENGTH
+                && prefix.iter().all(|b| matches!(b, b'a'..=b'z' | b'_')) =>
+        {
+            Ok(())
+        }
+        _ => Err(Error(ErrorKind::TypeIdPrefix)),
+    }
+}
+
+// The suffix is a base32 UUID, so its length and overflow errors are the same
+fn decode_suffix(suffix: &str, offset: usize) -> Result<Uuid, Error> {
+    let uuid = match base32::decode(suffix.as_bytes()) {
+        Ok(uuid) => uuid,
+        Err(Invalid::Length) => return Err(Error(ErrorKind::Base32Length { len: suffix.len() })),
+        Err(Invalid::Char(index)) => return Err(invalid_char(suffix, index, offset)),
+        Err(Invalid::Overflow) => return Err(Error(ErrorKind::Base32Overflow)),
+    };
+
+    // Base32 decoding also accepts upper-case and ambiguous characters,
+    // but the spec only allows the lower-case alphabet
+    match suffix
+        .bytes()
+        .position(|b| base32::LOWER[base32::DECODE[b as usize] as usize] != b)
+    {
+        Some(index) => Err(invalid_char(suffix, index, offset)),
+        None => Ok(uuid),
+    }
+}
+
+fn invalid_char(suffix: &str, index: usize, offset: usize) -> Error {
+    Error(ErrorKind::ParseChar {
+        // Every byte before the index is ASCII, so it's at a character boundary
+        character: suffix[index..].chars().next().unwrap(),
+        index: offset + index + 1,
+    })
+}
+
+impl FromStr for TypeId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TypeId::parse_str(s)
+    }
+}
+
+impl fmt::Display for TypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::MAX_LENGTH]))
+    }
+}
+
+impl fmt::Debug for TypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypeId")
+            .field("prefix", &self.prefix())
+            .field("uuid", &self.uuid)
+            .finish()
+    }
+}
+
+impl Default for TypeId {
+    #[inline]
+    fn default() -> Self {
+        TypeId::from_uuid(Uuid::nil())
+    }
+}
+
+impl From<Uuid> for TypeId {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        TypeId::from_uuid(uuid)
+    }
+}
+
+impl From<TypeId> for Uuid {
+    #[inline]
+    fn from(id: TypeId) -> Self {
+        id.into_uuid()
+    }
+}
+
+impl AsRef<Uuid> for TypeId {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    // Test vectors from the TypeID spec (`valid.yml`)
+    const VALID: &[(&str, &str, &str)] = &[
+        (
+            "00000000000000000000000000",
+            "",
+            "00000000-0000-0000-0000-000000000000",
+        ),
+        (
+            "00000000000000000000000001",
+            "",
+            "00000000-0000-0000-0000-000000000001",
+        ),
+        (
+            "0000000000000000000000000a",
+            "",
+            "00000000-0000-0000-0000-00000000000a",
+        ),
+        (
+            "0000000000000000000000000g",
+            "",
+            "00000000-0000-0000-0000-000000000010",
+        ),
+        (
+            "00000000000000000000000010",
+            "",
+            "00000000-0000-0000-0000-000000000020",
+        ),
+        (
+            "7zzzzzzzzzzzzzzzzzzzzzzzzz",
+            "",
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+        ),
+        (
+            "prefix_0123456789abcdefghjkmnpqrs",
+            "prefix",
+            "0110c853-1d09-52d8-d73e-1194e95b5f19",
+        ),
+        (
+            "prefix_01h455vb4pex5vsknk084sn02q",
+            "prefix",
+            "01890a5d-ac96-774b-bcce-b302099a8057",
+        ),
+        (
+            "pre_fix_00000000000000000000000000",
+            "pre_fix",
+            "00000000-0000-0000-0000-000000000000",
+        ),
+    ];
+
+    // Test vectors from the TypeID spec (`invalid.yml`)
+    const INVALID: &[&str] = &[
+        "PREFIX_00000000000000000000000000",
+        "12345_00000000000000000000000000",
+        "pre.fix_00000000000000000000000000",
+        "préfix_00000000000000000000000000",
+        "  prefix_00000000000000000000000000",
+        "abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijkl_00000000000000000000000000",
+        "_00000000000000000000000000",
+        "_",
+        "prefix_1234567890123456789012345",
+        "prefix_123456789012345678901234567",
+        "prefix_1234567890123456789012345 ",
+        "prefix_0123456789ABCDEFGHJKMNPQRS",
+        "prefix_123456789-123456789-123456",
+        "prefix_ooooooiiiiiiuuuuuuulllllll",
+        "prefix_i23456789ol23456789oi23456",
+        "prefix_123456789-0123456789-0123456",
+        "prefix_8zzzzzzzzzzzzzzzzzzzzzzzzz",
+        "_prefix_00000000000000000000000000",
+        "prefix__00000000000000000000000000",
+        "",
+    ];
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_valid() {
+        for &(typeid, prefix, uuid) in VALID {
+            let uuid = Uuid::parse_str(uuid).unwrap();
+
+            let parsed = TypeId::parse_str(typeid).unwrap();
+
+            assert_eq!(prefix, parsed.prefix(), "{}", typeid);
+            assert_eq!(uuid, parsed.into_uuid(), "{}", typeid);
+
+            assert_eq!(typeid, TypeId::new(prefix, uuid).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_invalid() {
+        for &typeid in INVALID {
+            assert!(TypeId::parse_str(typeid).is_err(), "{}", typeid);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_errors() {
+        assert_eq!(
+            Error(ErrorKind::TypeIdPrefix),
+            TypeId::parse_str("Prefix_00000000000000000000000000").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::Base32Length { len: 25 }),
+            TypeId::parse_str("prefix_0000000000000000000000000").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::Base32Overflow),
+            TypeId::parse_str("prefix_8zzzzzzzzzzzzzzzzzzzzzzzzz").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: 'U',
+                index: 9,
+            }),
+            TypeId::parse_str("prefix_0U000000000000000000000000").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::ParseChar {
+                character: 'o',
+                index: 9,
+            }),
+            TypeId::parse_str("prefix_0o000000000000000000000000").unwrap_err()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_prefix_max_length() {
+        let prefix = "abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijk";
+        assert_eq!(TypeId::MAX_PREFIX_LENGTH, prefix.len());
+
+        let id = TypeId::new(prefix, Uuid::max()).unwrap();
+        let encoded = id.to_string();
+
+        assert_eq!(TypeId::MAX_LENGTH, encoded.len());
+        assert_eq!(id, encoded.parse().unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_ord() {
+        let a = TypeId::new("a", Uuid::max()).unwrap();
+        let ab = TypeId::new("ab", Uuid::nil()).unwrap();
+        let b = TypeId::new("b", Uuid::nil()).unwrap();
+
+        assert!(a < ab);
+        assert!(ab < b);
+        assert!(TypeId::new("a", Uuid::nil()).unwrap() < a);
+    }
+}
@@ -0,0 +1,633 @@
+//! Heuristic guesses about which generator produced a UUID.
+//!
+//! **The results of this module are heuristic.** A UUID doesn't record where it
+//! came from, and most of its bits are random. The functions here only look for
+//! bit patterns that known generators are likely to leave behind, so they can
+//! easily be fooled, and a UUID can be consistent with several generators at once.
+//! Use them to narrow down an investigation, not to make decisions.
+//!
+//! [`fingerprint`] scores a single UUID. [`fingerprint_sequence`] scores a run of
+//! UUIDs from the same source, in the order they were generated, which can reveal
+//! much more about how counters and timestamps are maintained.
+//!
+//! The profiles considered are:
+//!
+//! - [`ContextV7`](crate::ContextV7) from this library, which shifts its counter around
+//!   the variant, leaving two bits of `rand_a` clear, and increments it within the millisecond.
+//! - PostgreSQL's `uuidv7()`, which stores a sub-millisecond fraction in `rand_a`.
+//! - Version 7 UUIDs converted from monotonic ULIDs, which increment their random bits.
+//! - Java's `UUID.nameUUIDFromBytes`, which produces version 3 UUIDs.
+//! - SQL Server's `NEWSEQUENTIALID()`, which byte-swaps a version 1 UUID.
+//! - libuuid's version 1 UUIDs with a random node ID, which has its multicast bit set.
+//!
+//! # Examples
+//!
+//! ```
+//! # use uuid::{Uuid, forensics::{self, Generator}};
+//! # fn main() -> Result<(), uuid::Error> {
+//! let uuid = Uuid::parse_str("b3bfc6b1-05a2-e811-80d3-00155d0a2f0b")?;
+//!
+//! let fingerprint = forensics::fingerprint(&uuid);
+//!
+//! assert_eq!(Some(Generator::SqlServerSequential), fingerprint.best().map(|c| c.generator()));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    std::fmt,
+    timestamp::{self, UUID_TICKS_BETWEEN_EPOCHS},
+    Uuid, Variant, Version,
+};
+
+/// A known UUID generator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Generator {
+    /// [`ContextV7`](crate::ContextV7) from this library, as used by [`Uuid::now_v7`](crate::Uuid::now_v7).
+    UuidContextV7,
+    /// PostgreSQL's `uuidv7()` function.
+    PostgresUuidV7,
+    /// A monotonic ULID converted into a version 7 UUID.
+    UlidV7,
+    /// Java's `UUID.nameUUIDFromBytes`.
+    JavaNameUuid,
+    /// SQL Server's `NEWSEQUENTIALID()` function.
+    SqlServerSequential,
+    /// libuuid's `uuid_generate_time` with a random node ID.
+    LibuuidV1,
+}
+
+const GENERATORS: [Generator; 6] = [
+    Generator::UuidContextV7,
+    Generator::PostgresUuidV7,
+    Generator::UlidV7,
+    Generator::JavaNameUuid,
+    Generator::SqlServerSequential,
+    Generator::LibuuidV1,
+];
+
+impl fmt::Display for Generator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Generator::UuidContextV7 => write!(f, "uuid ContextV7"),
+            Generator::PostgresUuidV7 => write!(f, "PostgreSQL uuidv7()"),
+            Generator::UlidV7 => write!(f, "ULID converted to v7"),
+            Generator::JavaNameUuid => write!(f, "Java UUID.nameUUIDFromBytes"),
+            Generator::SqlServerSequential => write!(f, "SQL Server NEWSEQUENTIALID()"),
+            Generator::LibuuidV1 => write!(f, "libuuid v1 with a random node ID"),
+        }
+    }
+}
+
+/// A generator that's consistent with the bit patterns in a UUID, along with a score.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Candidate {
+    generator: Generator,
+    score: u8,
+}
+
+impl Candidate {
+    /// The generator that may have produced the UUID.
+    pub const fn generator(&self) -> Generator {
+        self.generator
+    }
+
+    /// A heuristic score from 1 to 100.
+    ///
+    /// Higher scores mean more of the bit patterns left by the generator were found.
+    /// The score isn't a probability, and a low score doesn't rule the generator out.
+    pub const fn score(&self) -> u8 {
+        self.score
+    }
+}
+
+/// The candidate generators for a UUID, ordered from the highest score to the lowest.
+///
+/// **This is a heuristic.** See the [module docs](self) for details.
+///
+/// Generators that are inconsistent with the UUID, for example because it's the
+/// wrong version, aren't included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint {
+    candidates: [Candidate; GENERATORS.len()],
+    len: usize,
+}
+
+impl Fingerprint {
+    fn from_scores(scores: [u8; GENERATORS.len()]) -> Self {
+        let mut fingerprint = Fingerprint {
+            candidates: [Candidate {
+                generator: Generator::UuidContextV7,
+                score: 0,
+            }; GENERATORS.len()],
+            len: 0,
+        };
+
+        for (generator, score) in GENERATORS.iter().zip(scores) {
+            if score > 0 {
+                fingerprint.candidates[fingerprint.len] = Candidate {
+                    generator: *generator,
+                    score,
+                };
+                fingerprint.len += 1;
+            }
+        }
+
+        // Candidates with the same score keep the order they're declared in
+        fingerprint.candidates[..fingerprint.len].sort_unstable_by(|a, b| {
+            b.score.cmp(&a.score).then_with(|| {
+                let index = |c: &Candidate| GENERATORS.iter().position(|g| *g == c.generator);
+                index(a).cmp(&index(b))
+            })
+        });
+
+        fingerprint
+    }
+
+    /// Get the candidate with the highest score, if any are consistent with the UUID.
+    pub fn best(&self) -> Option<&Candidate> {
+        self.candidates().first()
+    }
+
+    /// Get all candidates that are consistent with the UUID, from the highest score to the lowest.
+    pub fn candidates(&self) -> &[Candidate] {
+        &self.candidates[..self.len]
+    }
+
+    /// Get the score for a specific generator, or `None` if it's inconsistent with the UUID.
+    pub fn score(&self, generator: Generator) -> Option<u8> {
+        self.candidates()
+            .iter()
+            .find(|c| c.generator == generator)
+            .map(|c| c.score)
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "heuristic:")?;
+
+        if self.len == 0 {
+            return write!(f, " no known generator");
+        }
+
+        for (i, candidate) in self.candidates().iter().enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+
+            write!(f, "{}{} ({})", sep, candidate.generator, candidate.score)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Score the generators that may have produced a UUID.
+///
+/// **This is a heuristic.** See the [module docs](self) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, forensics::{self, Generator}};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("017f22e2-79b0-73c3-98c4-dc0c0c07398f")?;
+///
+/// let fingerprint = forensics::fingerprint(&uuid);
+///
+/// assert_eq!(
+///     "heuristic: uuid ContextV7 (70), PostgreSQL uuidv7() (40), ULID converted to v7 (40)",
+///     fingerprint.to_string(),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn fingerprint(uuid: &Uuid) -> Fingerprint {
+    Fingerprint::from_scores(GENERATORS.map(|generator| score(generator, uuid)))
+}
+
+/// Score the generators that may have produced a sequence of UUIDs.
+///
+/// The UUIDs should come from the same source, in the order they were generated.
+/// Generators that are inconsistent with any UUID in the sequence aren't included.
+///
+/// **This is a heuristic.** See the [module docs](self) for details.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "v7", feature = "std"))] {
+/// # use uuid::{ContextV7, Timestamp, Uuid, forensics::{self, Generator}};
+/// let context = ContextV7::new();
+///
+/// let uuids = [
+///     Uuid::new_v7(Timestamp::from_unix(&context, 1_700_000_000, 0)),
+///     Uuid::new_v7(Timestamp::from_unix(&context, 1_700_000_000, 0)),
+///     Uuid::new_v7(Timestamp::from_unix(&context, 1_700_000_000, 0)),
+/// ];
+///
+/// let fingerprint = forensics::fingerprint_sequence(&uuids);
+///
+/// assert_eq!(Some(Generator::UuidContextV7), fingerprint.best().map(|c| c.generator()));
+/// # }
+/// ```
+pub fn fingerprint_sequence(uuids: &[Uuid]) -> Fingerprint {
+    Fingerprint::from_scores(GENERATORS.map(|generator| score_sequence(generator, uuids)))
+}
+
+// Scores are built from a base for being consistent with a generator,
+// plus points for each bit pattern that's specific to it
+fn score(generator: Generator, uuid: &Uuid) -> u8 {
+    if uuid.get_variant() != Variant::RFC4122 {
+        return 0;
+    }
+
+    match generator {
+        Generator::UuidContextV7 => match uuid.get_version() {
+            // Shifting the counter around the variant leaves a gap of two
+            // bits in `rand_a` that are always clear
+            Some(Version::SortRand) if v7_rand_a(uuid) & 0b1100 == 0 => 70,
+            _ => 0,
+        },
+        Generator::PostgresUuidV7 | Generator::UlidV7 => match uuid.get_version() {
+            Some(Version::SortRand) => 40,
+            _ => 0,
+        },
+        Generator::JavaNameUuid => match uuid.get_version() {
+            Some(Version::Md5) => 50,
+            _ => 0,
+        },
+        Generator::SqlServerSequential => {
+            let unswapped = unswap_sql_server(uuid);
+
+            if unswapped.get_version() != Some(Version::Mac) {
+                return 0;
+            }
+
+            let mut score = 20;
+
+            // The version nibble lands in a random-looking place when swapped,
+            // so a plausible timestamp once it's unswapped is strong evidence
+            if plausible_gregorian(&unswapped, SQL_SERVER_2005) {
+                score += 50;
+            }
+
+            // `NEWSEQUENTIALID()` uses the MAC address of a network card
+            if !is_multicast(&unswapped) {
+                score += 10;
+            }
+
+            score
+        }
+        Generator::LibuuidV1 => {
+            if uuid.get_version() != Some(Version::Mac) || !is_multicast(uuid) {
+                return 0;
+            }
+
+            if plausible_gregorian(uuid, YEAR_2000) {
+                60
+            } else {
+                40
+            }
+        }
+    }
+}
+
+fn score_sequence(generator: Generator, uuids: &[Uuid]) -> u8 {
+    let mut total = 0u32;
+
+    for uuid in uuids {
+        match score(generator, uuid) {
+            // A generator is ruled out if any UUID is inconsistent with it
+            0 => return 0,
+            score => total += score as u32,
+        }
+    }
+
+    let single = match uuids.len() {
+        0 => return 0,
+        len => (total / len as u32) as u8,
+    };
+
+    let pairs = uuids.windows(2).map(|pair| (&pair[0], &pair[1]));
+
+    match generator {
+        Generator::UuidContextV7 => score_steps(single, pairs, |prev, next| {
+            v7_counter(next) == v7_counter(prev) + 1
+        }),
+        Generator::PostgresUuidV7 => score_steps(single, pairs, |prev, next| {
+            // The sub-millisecond fraction increases, but the rest is random
+            v7_rand_a(next) > v7_rand_a(prev) && v7_counter(next) != v7_counter(prev) + 1
+        }),
+        Generator::UlidV7 => score_steps(single, pairs, |prev, next| {
+            v7_rand_a(next) == v7_rand_a(prev) && v7_rand_b(next) == v7_rand_b(prev) + 1
+        }),
+        Generator::JavaNameUuid => single,
+        Generator::SqlServerSequential => score_series(
+            single,
+            15,
+            pairs.map(|(a, b)| (unswap_sql_server(a), unswap_sql_server(b))),
+        ),
+        Generator::LibuuidV1 => score_series(single, 20, pairs.map(|(a, b)| (*a, *b))),
+    }
+}
+
+// Score a version 7 generator by how UUIDs within the same millisecond step
+fn score_steps<'a>(
+    single: u8,
+    pairs: impl Iterator<Item = (&'a Uuid, &'a Uuid)>,
+    step: impl Fn(&Uuid, &Uuid) -> bool,
+) -> u8 {
+    let mut same_millis = 0;
+    let mut matching = 0;
+
+    for (prev, next) in pairs {
+        if timestamp::decode_unix_timestamp_millis(prev)
+            == timestamp::decode_unix_timestamp_millis(next)
+        {
+            same_millis += 1;
+
+            if step(prev, next) {
+                matching += 1;
+            }
+        }
+    }
+
+    if same_millis == 0 {
+        return single;
+    }
+
+    (10 + 85 * matching / same_millis) as u8
+}
+
+// Score a version 1 generator by whether its node ID is fixed and its timestamps increase
+fn score_series(single: u8, bonus: u8, mut pairs: impl Iterator<Item = (Uuid, Uuid)>) -> u8 {
+    let mut any = false;
+
+    let consistent = pairs.all(|(prev, next)| {
+        any = true;
+
+        prev.get_node_id() == next.get_node_id()
+            && timestamp::decode_gregorian_timestamp(&prev).0
+                <= timestamp::decode_gregorian_timestamp(&next).0
+    });
+
+    if any && consistent {
+        single.saturating_add(bonus).min(95)
+    } else {
+        single
+    }
+}
+
+// The start of 2000, in 100ns ticks since the Gregorian epoch
+const YEAR_2000: u64 = UUID_TICKS_BETWEEN_EPOCHS + 946_684_800 * 10_000_000;
+
+// The start of 2005, when `NEWSEQUENTIALID()` was introduced in SQL Server 2005
+const SQL_SERVER_2005: u64 = UUID_TICKS_BETWEEN_EPOCHS + 1_104_537_600 * 10_000_000;
+
+// The start of 2100
+const YEAR_2100: u64 = UUID_TICKS_BETWEEN_EPOCHS + 4_102_444_800 * 10_000_000;
+
+fn plausible_gregorian(uuid: &Uuid, not_before: u64) -> bool {
+    let (ticks, _) = timestamp::decode_gregorian_timestamp(uuid);
+
+    (not_before..YEAR_2100).contains(&ticks)
+}
+
+fn is_multicast(uuid: &Uuid) -> bool {
+    uuid.as_bytes()[10] & 0x01 != 0
+}
+
+// `NEWSEQUENTIALID()` reverses the bytes of the first three fields of a version 1 UUID
+fn unswap_sql_server(uuid: &Uuid) -> Uuid {
+    let b = uuid.as_bytes();
+
+    Uuid::from_bytes([
+        b[3], b[2], b[1], b[0], b[5], b[4], b[7], b[6], b[8], b[9], b[10], b[11], b[12], b[13],
+        b[14], b[15],
+    ])
+}
+
+// The 12 bits of `rand_a` in a version 7 UUID
+fn v7_rand_a(uuid: &Uuid) -> u64 {
+    let b = uuid.as_bytes();
+
+    ((b[6] & 0x0f) as u64) << 8 | b[7] as u64
+}
+
+// The 62 bits of `rand_b` in a version 7 UUID
+fn v7_rand_b(uuid: &Uuid) -> u64 {
+    uuid.as_u64_pair().1 & (u64::MAX >> 2)
+}
+
+// The bits of the counter used by `ContextV7` that survive encoding: the `rand_a`
+// bits either side of the gap, and the top 26 bits of `rand_b`. The top bits of
+// the counter are overwritten by the version, and two more by the variant
+fn v7_counter(uuid: &Uuid) -> u64 {
+    let rand_a = v7_rand_a(uuid);
+
+    (rand_a >> 4) << 28 | (rand_a & 0b11) << 26 | v7_rand_b(uuid) >> 36
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::{std::string::ToString, Builder};
+
+    fn parse(uuid: &str) -> Uuid {
+        Uuid::parse_str(uuid).unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_v7() {
+        // The gap in the counter is clear
+        let result = fingerprint(&parse("017f22e2-79b0-73c3-98c4-dc0c0c07398f"));
+
+        assert_eq!(Some(70), result.score(Generator::UuidContextV7));
+        assert_eq!(Some(40), result.score(Generator::PostgresUuidV7));
+        assert_eq!(Some(40), result.score(Generator::UlidV7));
+        assert_eq!(None, result.score(Generator::JavaNameUuid));
+
+        // The gap in the counter is set
+        let result = fingerprint(&parse("017f22e2-79b0-7ec7-98c4-dc0c0c07398f"));
+
+        assert_eq!(
+            Generator::PostgresUuidV7,
+            result.best().unwrap().generator()
+        );
+        assert_eq!(None, result.score(Generator::UuidContextV7));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_v7_sequences() {
+        let millis = 1_700_000_000_000;
+
+        // `rand_a` is a sub-millisecond fraction, and `rand_b` is random
+        let postgres = [
+            Builder::from_unix_timestamp_millis(
+                millis,
+                &[0x01, 0x10, 0x9f, 0x2a, 0x11, 0x8c, 0x03, 0x4b, 0x77, 0x90],
+            )
+            .into_uuid(),
+            Builder::from_unix_timestamp_millis(
+                millis,
+                &[0x03, 0x52, 0x4e, 0x91, 0xc0, 0x27, 0xd5, 0x6e, 0x12, 0x3a],
+            )
+            .into_uuid(),
+            Builder::from_unix_timestamp_millis(
+                millis,
+                &[0x0a, 0x07, 0xb1, 0x5c, 0x64, 0xe8, 0x39, 0x0f, 0xaa, 0xd1],
+            )
+            .into_uuid(),
+        ];
+
+        assert_eq!(
+            Generator::PostgresUuidV7,
+            fingerprint_sequence(&postgres).best().unwrap().generator()
+        );
+
+        // The random bits increment
+        let ulid = [
+            Builder::from_unix_timestamp_millis(
+                millis,
+                &[0x01, 0x10, 0x9f, 0x2a, 0x11, 0x8c, 0x03, 0x4b, 0x77, 0x90],
+            )
+            .into_uuid(),
+            Builder::from_unix_timestamp_millis(
+                millis,
+                &[0x01, 0x10, 0x9f, 0x2a, 0x11, 0x8c, 0x03, 0x4b, 0x77, 0x91],
+            )
+            .into_uuid(),
+            Builder::from_unix_timestamp_millis(
+                millis,
+                &[0x01, 0x10, 0x9f, 0x2a, 0x11, 0x8c, 0x03, 0x4b, 0x77, 0x92],
+            )
+            .into_uuid(),
+        ];
+
+        let result = fingerprint_sequence(&ulid);
+
+        assert_eq!(Generator::UlidV7, result.best().unwrap().generator());
+        assert_eq!(Some(95), result.score(Generator::UlidV7));
+        assert_eq!(Some(10), result.score(Generator::UuidContextV7));
+    }
+
+    #[test]
+    #[cfg(all(feature = "v7", feature = "std"))]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_context_v7_sequence() {
+        use crate::{ContextV7, Timestamp};
+
+        let context = ContextV7::new();
+
+        let uuids = [
+            Uuid::new_v7(Timestamp::from_unix(&context, 1_700_000_000, 0)),
+            Uuid::new_v7(Timestamp::from_unix(&context, 1_700_000_000, 0)),
+            Uuid::new_v7(Timestamp::from_unix(&context, 1_700_000_000, 0)),
+            Uuid::new_v7(Timestamp::from_unix(&context, 1_700_000_001, 0)),
+            Uuid::new_v7(Timestamp::from_unix(&context, 1_700_000_001, 0)),
+        ];
+
+        let result = fingerprint_sequence(&uuids);
+
+        assert_eq!(Some(95), result.score(Generator::UuidContextV7));
+        assert_eq!(Some(10), result.score(Generator::UlidV7));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_java_name_uuid() {
+        // `UUID.nameUUIDFromBytes("hello".getBytes())`
+        let result = fingerprint(&parse("5d41402a-bc4b-3a76-b971-9d911017c592"));
+
+        assert_eq!(
+            "heuristic: Java UUID.nameUUIDFromBytes (50)",
+            result.to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_sql_server_sequential() {
+        let uuids = [
+            parse("b3bfc6b1-05a2-e811-80d3-00155d0a2f0b"),
+            parse("b4bfc6b1-05a2-e811-80d3-00155d0a2f0b"),
+            parse("b5bfc6b1-05a2-e811-80d3-00155d0a2f0b"),
+        ];
+
+        assert_eq!(
+            Some(80),
+            fingerprint(&uuids[0]).score(Generator::SqlServerSequential)
+        );
+        assert_eq!(
+            Some(95),
+            fingerprint_sequence(&uuids).score(Generator::SqlServerSequential)
+        );
+
+        // A version 1 UUID that hasn't been swapped
+        assert_eq!(
+            None,
+            fingerprint(&parse("b1c6bfb3-a205-11e8-80d3-00155d0a2f0b"))
+                .score(Generator::SqlServerSequential)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_libuuid_v1() {
+        let uuids = [
+            parse("f9168c5e-ceb2-11ea-b6bf-c3155d8c7b0f"),
+            parse("f9168c5f-ceb2-11ea-b6bf-c3155d8c7b0f"),
+        ];
+
+        assert_eq!(Some(60), fingerprint(&uuids[0]).score(Generator::LibuuidV1));
+        assert_eq!(
+            Some(80),
+            fingerprint_sequence(&uuids).score(Generator::LibuuidV1)
+        );
+
+        // A hardware MAC address
+        assert_eq!(
+            "heuristic: no known generator",
+            fingerprint(&parse("f9168c5e-ceb2-11ea-b6bf-00155d8c7b0f")).to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_unknown() {
+        assert!(fingerprint(&Uuid::nil()).best().is_none());
+        assert!(fingerprint(&Uuid::max()).best().is_none());
+        assert!(fingerprint(&parse("67e55044-10b1-426f-9247-bb680e5fe0c8"))
+            .best()
+            .is_none());
+        assert!(fingerprint_sequence(&[]).best().is_none());
+    }
+}
@@ -111,6 +111,7 @@
 //! * `rng-rand` - forces `rand` as the backend for randomness.
 //! * `rng-getrandom` - forces `getrandom` as the backend for randomness.
 //! * `bytemuck` - adds a `Pod` trait implementation to `Uuid` for byte manipulation
+//! * `derive` - adds the [`UuidNewtype`] derive for newtypes like `struct UserId(Uuid)`.
 //!
 //! # Unstable features
 //!
@@ -226,12 +227,23 @@ extern crate core as std;
 mod macros;
 
 mod builder;
+mod cassandra;
 mod error;
+mod ncs;
 mod non_nil;
 mod parser;
+mod sql_server;
+mod time_ordered;
+mod ulid;
+mod versioned;
 
+pub mod abbrev;
+pub mod decode;
 pub mod fmt;
+pub mod forensics;
 pub mod timestamp;
+pub mod typed;
+pub mod validate;
 
 use core::hash::{Hash, Hasher};
 pub use timestamp::{context::NoContext, ClockSequence, Timestamp};
@@ -276,9 +288,67 @@ mod external;
 #[doc(hidden)]
 pub mod __macro_support {
     pub use crate::std::result::Result::{Err, Ok};
+
+    #[cfg(feature = "serde")]
+    pub use serde_core as serde;
 }
 
-pub use crate::{builder::Builder, error::Error, non_nil::NonNilUuid};
+pub use crate::{
+    builder::Builder,
+    error::Error,
+    ncs::NcsHost,
+    non_nil::NonNilUuid,
+    sql_server::SqlServerOrdered,
+    time_ordered::TimeOrdered,
+    typed::TypedUuid,
+    versioned::{UuidV1, UuidV3, UuidV4, UuidV5, UuidV6, UuidV7, UuidV8},
+};
+
+/// Derive conversions, formatting, and parsing for a UUID newtype.
+///
+/// The derive applies to tuple structs with a single [`Uuid`] field, like
+/// `struct UserId(Uuid)`. It generates:
+///
+/// - `as_uuid` and `into_uuid` methods.
+/// - `Display` and `FromStr` implementations.
+/// - `From<UserId> for Uuid` and `AsRef<Uuid>` implementations.
+/// - Either `From<Uuid>`, or `TryFrom<Uuid>` if the version is restricted.
+///
+/// The derive can be configured with a `#[uuid(...)]` attribute:
+///
+/// - `version = 7` only accepts UUIDs with the given version when parsing or
+///   converting. It also generates constructors for the version, like `UserId::now_v7()`,
+///   when the matching features of this crate are enabled.
+/// - `format = "simple"` formats and parses using an adapter from [`fmt`] instead of
+///   the hyphenated format. One of `hyphenated`, `simple`, `urn`, or `braced`.
+/// - `serde` implements `Serialize` and `Deserialize`, using the `format` if one is set.
+///   A module from [`serde`] can be given instead, like `serde = "compact"`.
+///   This requires the `serde` feature.
+/// - `crate = "path::to::uuid"` sets the path to this crate if it's been renamed.
+///
+/// This derive requires the `derive` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "v7")] {
+/// use uuid::{Uuid, UuidNewtype};
+///
+/// #[derive(UuidNewtype, Debug, Clone, Copy, PartialEq)]
+/// #[uuid(version = 7, format = "simple")]
+/// struct OrderId(Uuid);
+///
+/// let id: OrderId = "017f22e279b07cc398c4dc0c0c07398f".parse()?;
+///
+/// assert_eq!("017f22e279b07cc398c4dc0c0c07398f", id.to_string());
+///
+/// // Other versions are rejected
+/// assert!(OrderId::try_from(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?).is_err());
+/// # }
+/// # Ok::<(), uuid::Error>(())
+/// ```
+#[cfg(feature = "derive")]
+pub use uuid_derive::UuidNewtype;
 
 /// A 128-bit (16 byte) buffer containing the UUID.
 ///
@@ -327,7 +397,7 @@ pub enum Version {
 /// # References
 ///
 /// * [Variant Field in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-4.1)
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum Variant {
@@ -854,6 +924,46 @@ impl Uuid {
         ]
     }
 
+    /// Returns the bytes of the UUID with the time-low and time-high fields swapped.
+    ///
+    /// This is the binary encoding produced by MySQL's `UUID_TO_BIN(uuid, 1)` function.
+    /// For version 1 UUIDs, moving the most significant bits of the timestamp to the
+    /// front means values stored in a `BINARY(16)` column will be inserted into an index
+    /// sequentially.
+    ///
+    /// The original UUID can be recovered using [`Uuid::from_mysql_swapped_bytes`],
+    /// which is equivalent to MySQL's `BIN_TO_UUID(bytes, 1)` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("6ccd780c-baba-1026-9564-5b8c656024db")?;
+    ///
+    /// assert_eq!(
+    ///     uuid.to_mysql_swapped_bytes(),
+    ///     [
+    ///         0x10, 0x26, 0xba, 0xba, 0x6c, 0xcd, 0x78, 0x0c, 0x95, 0x64,
+    ///         0x5b, 0x8c, 0x65, 0x60, 0x24, 0xdb,
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [`UUID_TO_BIN` in the MySQL reference manual](https://dev.mysql.com/doc/refman/8.0/en/miscellaneous-functions.html#function_uuid-to-bin)
+    pub const fn to_mysql_swapped_bytes(&self) -> Bytes {
+        [
+            self.0[6], self.0[7], self.0[4], self.0[5], self.0[0], self.0[1], self.0[2], self.0[3],
+            self.0[8], self.0[9], self.0[10], self.0[11], self.0[12], self.0[13], self.0[14],
+            self.0[15],
+        ]
+    }
+
     /// Tests if the UUID is nil (all zeros).
     pub const fn is_nil(&self) -> bool {
         self.as_u128() == u128::MIN
@@ -894,14 +1004,16 @@ impl Uuid {
     }
 
     /// If the UUID is the correct version (v1, v6, or v7) this will return
-    /// the timestamp in a version-agnostic [`Timestamp`]. For other versions
-    /// this will return `None`.
+    /// the timestamp in a version-agnostic [`Timestamp`]. The timestamp of a
+    /// legacy NCS variant UUID is also returned, as long as its reserved bits
+    /// are zero. For other versions this will return `None`.
     ///
     /// # Roundtripping
     ///
     /// This method is unlikely to roundtrip a timestamp in a UUID due to the way
     /// UUIDs encode timestamps. The timestamp returned from this method will be truncated to
-    /// 100ns precision for version 1 and 6 UUIDs, and to millisecond precision for version 7 UUIDs.
+    /// 100ns precision for version 1 and 6 UUIDs, to millisecond precision for version 7 UUIDs,
+    /// and to 4µs precision for NCS variant UUIDs.
     pub const fn get_timestamp(&self) -> Option<Timestamp> {
         match self.get_version() {
             Some(Version::Mac) => {
@@ -922,6 +1034,13 @@ impl Uuid {
 
                 Some(Timestamp::from_unix_time(seconds, nanos, 0, 0))
             }
+            // NCS variant UUIDs don't have a version; their reserved bits, which
+            // overlap the version nibble, are always zero
+            _ if self.is_ncs_layout() => {
+                let ticks = timestamp::decode_ncs_timestamp(self);
+
+                Some(Timestamp::from_ncs_time(ticks))
+            }
             _ => None,
         }
     }
@@ -945,6 +1064,95 @@ impl Uuid {
             _ => None,
         }
     }
+
+    /// If the UUID is a version 1 UUID this will return the equivalent version 6 UUID.
+    /// For other versions this will return `None`.
+    ///
+    /// The conversion is lossless. The timestamp is reordered so the result sorts by its
+    /// timestamp, but the clock sequence, variant and node ID are kept exactly, so
+    /// [`Uuid::to_v1`] will return the original UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let v1 = Uuid::parse_str("c232ab00-9414-11ec-b3c8-9f6bdeced846")?;
+    /// let v6 = v1.to_v6().unwrap();
+    ///
+    /// assert_eq!(Some(Version::SortMac), v6.get_version());
+    /// assert_eq!("1ec9414c-232a-6b00-b3c8-9f6bdeced846", v6.to_string());
+    ///
+    /// assert_eq!(v1.get_timestamp(), v6.get_timestamp());
+    /// assert_eq!(Some(v1), v6.to_v1());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [UUID Version 6 in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-5.6)
+    pub const fn to_v6(&self) -> Option<Uuid> {
+        match self.get_version() {
+            Some(Version::Mac) => {
+                let (ticks, _) = timestamp::decode_gregorian_timestamp(self);
+
+                Some(
+                    self.with_gregorian_ticks(timestamp::encode_sorted_gregorian_timestamp(
+                        ticks, 0, &[0; 6],
+                    )),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    /// If the UUID is a version 6 UUID this will return the equivalent version 1 UUID.
+    /// For other versions this will return `None`.
+    ///
+    /// The conversion is lossless. The clock sequence, variant and node ID are kept exactly,
+    /// so [`Uuid::to_v6`] will return the original UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let v6 = Uuid::parse_str("1ec9414c-232a-6b00-b3c8-9f6bdeced846")?;
+    /// let v1 = v6.to_v1().unwrap();
+    ///
+    /// assert_eq!(Some(Version::Mac), v1.get_version());
+    /// assert_eq!("c232ab00-9414-11ec-b3c8-9f6bdeced846", v1.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_v1(&self) -> Option<Uuid> {
+        match self.get_version() {
+            Some(Version::SortMac) => {
+                let (ticks, _) = timestamp::decode_sorted_gregorian_timestamp(self);
+
+                Some(
+                    self.with_gregorian_ticks(timestamp::encode_gregorian_timestamp(
+                        ticks, 0, &[0; 6],
+                    )),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    // Take the timestamp and version from `encoded`, and everything else from `self`
+    const fn with_gregorian_ticks(&self, encoded: Uuid) -> Uuid {
+        let mut bytes = encoded.into_bytes();
+
+        let mut i = 8;
+        while i < 16 {
+            bytes[i] = self.0[i];
+            i += 1;
+        }
+
+        Uuid::from_bytes(bytes)
+    }
 }
 
 impl Hash for Uuid {
@@ -998,7 +1206,9 @@ pub mod serde {
     //! to change the way a [`Uuid`](../struct.Uuid.html) is serialized
     //! and deserialized.
 
-    pub use crate::external::serde_support::{braced, compact, hyphenated, simple, urn};
+    pub use crate::external::serde_support::{
+        base32, base64url, braced, compact, hyphenated, mysql_swapped, simple, urn,
+    };
 }
 
 #[cfg(test)]
@@ -1374,6 +1584,158 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_v7_bounds() {
+        let ts = Timestamp::from_unix_time(1_496_854_535, 812_946_000, 0, 0);
+
+        let min = Uuid::v7_min_for(ts);
+        let max = Uuid::v7_max_for(ts);
+
+        for uuid in [min, max] {
+            assert_eq!(Some(Version::SortRand), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+            assert_eq!(
+                (1_496_854_535, 812_000_000),
+                uuid.get_timestamp().unwrap().to_unix()
+            );
+        }
+
+        for random_bytes in [[0; 10], [0x5a; 10], [0xff; 10]] {
+            let uuid =
+                Builder::from_unix_timestamp_millis(1_496_854_535_812, &random_bytes).into_uuid();
+
+            assert!(min <= uuid && uuid <= max);
+        }
+
+        // The neighbouring milliseconds fall outside the bounds
+        let before = Timestamp::from_unix_time(1_496_854_535, 811_999_999, 0, 0);
+        let after = Timestamp::from_unix_time(1_496_854_535, 813_000_000, 0, 0);
+
+        assert!(Uuid::v7_max_for(before) < min);
+        assert!(Uuid::v7_min_for(after) > max);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_v7_range() {
+        let start = Timestamp::from_unix_time(1_496_854_535, 0, 0, 0);
+        let end = Timestamp::from_unix_time(1_496_854_536, 0, 0, 0);
+
+        let range = Uuid::v7_range(start..end);
+
+        assert_eq!(Uuid::v7_min_for(start), *range.start());
+        assert_eq!(
+            Uuid::v7_max_for(Timestamp::from_unix_time(1_496_854_535, 999_000_000, 0, 0)),
+            *range.end()
+        );
+        assert!(!range.contains(&Uuid::v7_min_for(end)));
+
+        let range = Uuid::v7_range(start..=end);
+
+        assert_eq!(Uuid::v7_max_for(end), *range.end());
+        assert!(range.contains(&Uuid::v7_min_for(end)));
+
+        let range = Uuid::v7_range(..);
+
+        assert_eq!(
+            Uuid::v7_min_for(Timestamp::from_unix_time(0, 0, 0, 0)),
+            *range.start()
+        );
+        assert_eq!(
+            Uuid::v7_max_for(Timestamp::from_unix_time(u64::MAX, 0, 0, 0)),
+            *range.end()
+        );
+
+        // Empty ranges
+        assert!(Uuid::v7_range(start..start).is_empty());
+        assert!(Uuid::v7_range(end..start).is_empty());
+        assert!(Uuid::v7_range(..Timestamp::from_unix_time(0, 0, 0, 0)).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_v6_bounds() {
+        let ts = Timestamp::from_unix_time(1_496_854_535, 812_946_000, 0, 0);
+
+        let min = Uuid::v6_min_for(ts);
+        let max = Uuid::v6_max_for(ts);
+
+        for uuid in [min, max] {
+            assert_eq!(Some(Version::SortMac), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+            assert_eq!(
+                ts.to_gregorian().0,
+                uuid.get_timestamp().unwrap().to_gregorian().0
+            );
+        }
+
+        assert!(min < some_uuid_v6() && some_uuid_v6() < max);
+
+        let range = Uuid::v6_range(ts..);
+
+        assert_eq!(min, *range.start());
+        assert_eq!(
+            "ffffffff-ffff-6fff-bfff-ffffffffffff",
+            range.end().hyphenated().to_string()
+        );
+        assert!(range.contains(&some_uuid_v6()));
+
+        let range = Uuid::v6_range(..ts);
+
+        assert!(!range.contains(&some_uuid_v6()));
+        assert!(Uuid::v6_range(ts..ts).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_v1_v6_conversion() {
+        let v1 = some_uuid_v1();
+        let v6 = some_uuid_v6();
+
+        assert_eq!(Some(v6), v1.to_v6());
+        assert_eq!(Some(v1), v6.to_v1());
+
+        assert_eq!(v1.get_timestamp(), v6.get_timestamp());
+        assert_eq!(v1.get_node_id(), v6.get_node_id());
+
+        // The clock sequence and non-standard variants are preserved
+        let v1 = Uuid::parse_str("20616934-4ba2-11e7-ffff-010203040506").unwrap();
+        let v6 = v1.to_v6().unwrap();
+
+        assert_eq!(Variant::Future, v6.get_variant());
+        assert_eq!(v1.as_fields().3, v6.as_fields().3);
+        assert_eq!(Some(v1), v6.to_v1());
+
+        for uuid in [
+            some_uuid_nil(),
+            some_uuid_v3(),
+            some_uuid_v4(),
+            some_uuid_v5(),
+            some_uuid_v7(),
+            some_uuid_v8(),
+            some_uuid_max(),
+        ] {
+            assert_eq!(None, uuid.to_v6());
+            assert_eq!(None, uuid.to_v1());
+        }
+
+        assert_eq!(None, some_uuid_v6().to_v6());
+        assert_eq!(None, some_uuid_v1().to_v1());
+    }
+
     #[test]
     #[cfg_attr(
         all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
@@ -1691,6 +2053,37 @@ mod tests {
         assert_eq!(&b_in, b_out);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_mysql_swapped_bytes() {
+        // From the MySQL reference manual for `UUID_TO_BIN`
+        let uuid = Uuid::parse_str("6ccd780c-baba-1026-9564-5b8c656024db").unwrap();
+
+        assert_eq!(
+            "1026baba6ccd780c95645b8c656024db",
+            Uuid::from_bytes(uuid.to_mysql_swapped_bytes())
+                .simple()
+                .to_string()
+        );
+
+        for uuid in some_uuid_iter() {
+            assert_eq!(
+                uuid,
+                Uuid::from_mysql_swapped_bytes(uuid.to_mysql_swapped_bytes())
+            );
+        }
+
+        // Swapped version 1 UUIDs sort by their timestamp
+        let a = Builder::from_gregorian_timestamp(0x01e7_4ba2_ffff_ffff, 0, &[0; 6]).into_uuid();
+        let b = Builder::from_gregorian_timestamp(0x01e7_4ba3_0000_0000, 0, &[0; 6]).into_uuid();
+
+        assert!(a > b);
+        assert!(a.to_mysql_swapped_bytes() < b.to_mysql_swapped_bytes());
+    }
+
     #[test]
     #[cfg_attr(
         all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
@@ -42,6 +42,74 @@ macro_rules! uuid {
     }};
 }
 
+/// Parse [`NonNilUuid`][uuid::NonNilUuid]s from string literals at compile time.
+///
+/// ## Usage
+///
+/// This macro works like [`uuid!`], but also raises a compilation error if
+/// the UUID is nil.
+///
+/// ## Examples
+///
+/// Setting a global constant:
+///
+/// ```
+/// # use uuid::{non_nil_uuid, NonNilUuid};
+/// pub const SCHEMA_ATTR_CLASS: NonNilUuid = non_nil_uuid!("00000000-0000-0000-0000-ffff00000000");
+/// ```
+///
+/// A nil UUID fails to compile:
+///
+/// ```compile_fail
+/// # use uuid::{non_nil_uuid, NonNilUuid};
+/// pub const NIL: NonNilUuid = non_nil_uuid!("00000000-0000-0000-0000-000000000000");
+/// ```
+///
+/// [uuid::NonNilUuid]: https://docs.rs/uuid/*/uuid/struct.NonNilUuid.html
+#[macro_export]
+macro_rules! non_nil_uuid {
+    ($uuid:expr) => {{
+        const OUTPUT: $crate::NonNilUuid = match $crate::NonNilUuid::try_parse($uuid) {
+            $crate::__macro_support::Ok(u) => u,
+            $crate::__macro_support::Err(_) => panic!("invalid or nil UUID"),
+        };
+        OUTPUT
+    }};
+}
+
+// Hidden macros used by the `UuidNewtype` derive
+
+// Each macro either expands to its input or to nothing, depending on
+// the features enabled on this crate
+macro_rules! define_feature_gate {
+    ($d:tt $name:ident, $($cfg:meta),+) => {
+        #[cfg(all($($cfg),+))]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! $name {
+            ($d($d tt:tt)*) => { $d($d tt)* };
+        }
+
+        #[cfg(not(all($($cfg),+)))]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! $name {
+            ($d($d tt:tt)*) => {};
+        }
+    };
+}
+
+define_feature_gate!($ __uuid_if_v1, feature = "v1");
+define_feature_gate!($ __uuid_if_v1_now, feature = "v1", feature = "std", feature = "rng");
+define_feature_gate!($ __uuid_if_v3, feature = "v3");
+define_feature_gate!($ __uuid_if_v4, feature = "v4");
+define_feature_gate!($ __uuid_if_v5, feature = "v5");
+define_feature_gate!($ __uuid_if_v6, feature = "v6");
+define_feature_gate!($ __uuid_if_v6_now, feature = "v6", feature = "std", feature = "rng");
+define_feature_gate!($ __uuid_if_v7, feature = "v7");
+define_feature_gate!($ __uuid_if_v7_now, feature = "v7", feature = "std");
+define_feature_gate!($ __uuid_if_v8, feature = "v8");
+
 // Internal macros
 
 // These `transmute` macros are a stepping stone towards `zerocopy` integration.
@@ -0,0 +1,251 @@
+//! Compatibility with legacy NCS variant UUIDs.
+//!
+//! Before UUIDs were standardized, Apollo's Network Computing System (NCS) used
+//! a 128-bit identifier with a different layout:
+//!
+//! * A 48-bit timestamp, as the number of 4µs ticks since `1980-01-01 00:00:00`.
+//! * 16 reserved bits.
+//! * An 8-bit address family, whose most significant bit is clear. This is the
+//!   bit that [`Variant::NCS`](crate::Variant::NCS) is detected from.
+//! * A 56-bit host ID.
+//!
+//! These identifiers can still be found in DCE RPC interface IDs and archived data.
+//!
+//! # References
+//!
+//! * [Variant Field in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-4.1)
+//! * [DCE 1.1: Remote Procedure Call, Appendix A](https://pubs.opengroup.org/onlinepubs/9629399/apdxa.htm)
+
+use crate::{timestamp, Builder, Timestamp, Uuid, Variant};
+
+/// The address family and host ID of a legacy NCS variant UUID.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{NcsHost, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("333a2276-0000-0000-0d00-00809c000000")?;
+///
+/// let host = uuid.get_ncs_host().unwrap();
+///
+/// assert_eq!(13, host.family());
+/// assert_eq!([0x00, 0x00, 0x80, 0x9c, 0x00, 0x00, 0x00], host.host_id());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NcsHost {
+    family: u8,
+    host_id: [u8; 7],
+}
+
+impl NcsHost {
+    /// Create an `NcsHost` from an address family and host ID.
+    ///
+    /// Only the least significant 7 bits of the `family` are used. The most
+    /// significant bit is always clear in an NCS variant UUID.
+    pub const fn new(family: u8, host_id: [u8; 7]) -> Self {
+        NcsHost {
+            family: family & 0x7F,
+            host_id,
+        }
+    }
+
+    /// The network address family of the host.
+    pub const fn family(&self) -> u8 {
+        self.family
+    }
+
+    /// The 56-bit ID of the host within its address family.
+    pub const fn host_id(&self) -> [u8; 7] {
+        self.host_id
+    }
+}
+
+impl Uuid {
+    /// Create a new legacy NCS variant UUID using the given timestamp and host.
+    ///
+    /// NCS variant UUIDs are obsolete, so new ones should only be created to test
+    /// compatibility with systems that still handle them. Only the least significant
+    /// 48 bits of the timestamp, as returned by [`Timestamp::to_ncs`], are stored,
+    /// so timestamps after mid-2015 will wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{NcsHost, Timestamp, Uuid, Variant};
+    /// let host = NcsHost::new(13, [0x00, 0x00, 0x80, 0x9c, 0x00, 0x00, 0x00]);
+    ///
+    /// let uuid = Uuid::new_ncs(Timestamp::from_unix_time(540_831_917, 113_344_000, 0, 0), &host);
+    ///
+    /// assert_eq!("333a2276-0000-0000-0d00-00809c000000", uuid.to_string());
+    /// assert_eq!(Variant::NCS, uuid.get_variant());
+    /// ```
+    pub const fn new_ncs(ts: Timestamp, host: &NcsHost) -> Uuid {
+        Builder::from_ncs_timestamp(ts.to_ncs(), host).into_uuid()
+    }
+
+    /// If the UUID is a legacy NCS variant UUID this will return its address
+    /// family and host ID. For other variants, the nil UUID, or an NCS variant
+    /// UUID whose reserved bits aren't zero, this will return `None`.
+    ///
+    /// The timestamp of an NCS variant UUID is returned by [`Uuid::get_timestamp`].
+    pub const fn get_ncs_host(&self) -> Option<NcsHost> {
+        if self.is_ncs_layout() {
+            let bytes = self.as_bytes();
+
+            Some(NcsHost {
+                family: bytes[8],
+                host_id: [
+                    bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+                ],
+            })
+        } else {
+            None
+        }
+    }
+
+    // Whether the UUID has the layout written by `Builder::from_ncs_timestamp`
+    //
+    // The variant bit alone matches half of all 128-bit values, so the reserved
+    // bits are also required to be zero. This keeps NCS values from overlapping
+    // with RFC 9562 versions, whose version nibble sits in those bits.
+    pub(crate) const fn is_ncs_layout(&self) -> bool {
+        let bytes = self.as_bytes();
+
+        matches!(self.get_variant(), Variant::NCS)
+            && !self.is_nil()
+            && bytes[6] == 0
+            && bytes[7] == 0
+    }
+}
+
+impl Builder {
+    /// Creates a `Builder` for a legacy NCS variant UUID using the supplied timestamp and host.
+    ///
+    /// The `ticks` are the number of 4µs ticks since `1980-01-01 00:00:00`. Only their least
+    /// significant 48 bits are stored. The reserved bits are set to zero.
+    pub const fn from_ncs_timestamp(ticks: u64, host: &NcsHost) -> Self {
+        Builder::from_bytes(
+            *timestamp::encode_ncs_timestamp(ticks, host.family, &host.host_id).as_bytes(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{std::string::ToString, Version};
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_decode_ncs() {
+        let uuid = Uuid::parse_str("333a2276-0000-0000-0d00-00809c000000").unwrap();
+
+        assert_eq!(Variant::NCS, uuid.get_variant());
+        assert_eq!(None, uuid.get_version());
+        assert_eq!(None, uuid.get_node_id());
+
+        let ts = uuid.get_timestamp().unwrap();
+
+        assert_eq!(0x333a_2276_0000, ts.to_ncs());
+        assert_eq!((540_831_917, 113_344_000), ts.to_unix());
+
+        assert_eq!(
+            NcsHost::new(0x0d, [0x00, 0x00, 0x80, 0x9c, 0x00, 0x00, 0x00]),
+            uuid.get_ncs_host().unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_ncs_variant_without_layout() {
+        // A version 1 nibble in the reserved bits is decoded as a version 1 UUID
+        let v1 = Uuid::parse_str("01234567-89ab-1def-1234-56789abcdef0").unwrap();
+
+        assert_eq!(Variant::NCS, v1.get_variant());
+        assert_eq!(Some(Version::Mac), v1.get_version());
+        assert_eq!(None, v1.get_ncs_host());
+
+        let ts = v1.get_timestamp().unwrap();
+
+        assert_eq!((0x0def_89ab_0123_4567, 0x1234), ts.to_gregorian());
+
+        // Arbitrary values with the NCS variant bit aren't NCS UUIDs
+        let other = Uuid::from_u128(0x1ec9414c_232a_4b00_33c8_9f6bdeced846);
+
+        assert_eq!(Variant::NCS, other.get_variant());
+        assert_eq!(None, other.get_timestamp());
+        assert_eq!(None, other.get_ncs_host());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_new_ncs_roundtrip() {
+        let host = NcsHost::new(0x02, [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]);
+        let ts = Timestamp::from_ncs_time(0x0000_1234_5678_9abc);
+
+        let uuid = Uuid::new_ncs(ts, &host);
+
+        assert_eq!("12345678-9abc-0000-0201-020304050607", uuid.to_string());
+        assert_eq!(Variant::NCS, uuid.get_variant());
+        assert_eq!(Some(ts), uuid.get_timestamp());
+        assert_eq!(Some(host), uuid.get_ncs_host());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_ncs_family_high_bit_cleared() {
+        let host = NcsHost::new(0xff, [0; 7]);
+
+        assert_eq!(0x7f, host.family());
+
+        let uuid = Builder::from_ncs_timestamp(1, &host).into_uuid();
+
+        assert_eq!(Variant::NCS, uuid.get_variant());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_ncs_timestamp_wraps() {
+        let ts = Timestamp::from_ncs_time(1 << 48);
+
+        let uuid = Uuid::new_ncs(ts, &NcsHost::new(0x02, [0; 7]));
+
+        assert_eq!(Timestamp::from_ncs_time(0), uuid.get_timestamp().unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_non_ncs() {
+        assert_eq!(None, Uuid::nil().get_timestamp());
+        assert_eq!(None, Uuid::nil().get_ncs_host());
+
+        let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        assert_eq!(None, uuid.get_ncs_host());
+    }
+}
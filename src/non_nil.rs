@@ -1,10 +1,11 @@
 //! A wrapper type for nil UUIDs that provides a more memory-efficient
 //! `Option<NonNilUuid>` representation.
 
-use std::{cmp::Ordering, fmt, num::NonZeroU128};
+use std::{cmp::Ordering, fmt, num::NonZeroU128, str::FromStr};
 
 use crate::{
     error::{Error, ErrorKind},
+    fmt::{Braced, Hyphenated, Simple, Urn},
     Uuid,
 };
 
@@ -26,13 +27,17 @@ use crate::{
 /// - [`Uuid::now_v7`]
 /// - [`Uuid::new_v8`]
 ///
+/// `NonNilUuid` has equivalent constructors, like [`NonNilUuid::new_v4`], that
+/// don't need to check for nil.
+///
 /// # ABI
 ///
 /// The `NonNilUuid` type does not yet have a stable ABI. Its representation or alignment
 /// may change. It is currently only guaranteed that `NonNilUuid` and `Option<NonNilUuid>`
 /// are the same size as `Uuid`.
 #[repr(transparent)]
-#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+// The value is stored in native-endian order, so its bytes in memory are the same as `Uuid`
 pub struct NonNilUuid(NonZeroU128);
 
 impl fmt::Debug for NonNilUuid {
@@ -47,6 +52,18 @@ impl fmt::Display for NonNilUuid {
     }
 }
 
+impl fmt::LowerHex for NonNilUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&Uuid::from(*self), f)
+    }
+}
+
+impl fmt::UpperHex for NonNilUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&Uuid::from(*self), f)
+    }
+}
+
 impl PartialEq<Uuid> for NonNilUuid {
     fn eq(&self, other: &Uuid) -> bool {
         self.get() == *other
@@ -59,6 +76,18 @@ impl PartialEq<NonNilUuid> for Uuid {
     }
 }
 
+impl PartialOrd for NonNilUuid {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NonNilUuid {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
 impl PartialOrd<Uuid> for NonNilUuid {
     fn partial_cmp(&self, other: &Uuid) -> Option<Ordering> {
         self.get().partial_cmp(other)
@@ -74,7 +103,7 @@ impl PartialOrd<NonNilUuid> for Uuid {
 impl NonNilUuid {
     /// Creates a non-nil UUID if the value is non-nil.
     pub const fn new(uuid: Uuid) -> Option<Self> {
-        match NonZeroU128::new(uuid.as_u128()) {
+        match NonZeroU128::new(u128::from_ne_bytes(uuid.into_bytes())) {
             Some(non_nil) => Some(NonNilUuid(non_nil)),
             None => None,
         }
@@ -86,13 +115,194 @@ impl NonNilUuid {
     ///
     /// The value must not be nil.
     pub const unsafe fn new_unchecked(uuid: Uuid) -> Self {
-        NonNilUuid(unsafe { NonZeroU128::new_unchecked(uuid.as_u128()) })
+        NonNilUuid(unsafe { NonZeroU128::new_unchecked(u128::from_ne_bytes(uuid.into_bytes())) })
+    }
+
+    /// Parses a `NonNilUuid` from a string of hexadecimal digits with optional hyphens.
+    ///
+    /// This method accepts the same formats as [`Uuid::parse_str`], and
+    /// will return an error if the UUID is nil.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::NonNilUuid;
+    /// let uuid = NonNilUuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    ///
+    /// assert!(uuid.is_ok());
+    /// assert!(NonNilUuid::parse_str("00000000-0000-0000-0000-000000000000").is_err());
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        NonNilUuid::try_from(Uuid::parse_str(input)?)
+    }
+
+    /// Parses a `NonNilUuid` from a string of hexadecimal digits with optional hyphens.
+    ///
+    /// This function is similar to [`parse_str`], in fact `parse_str` shares
+    /// the same underlying parser. The difference is that if `try_parse`
+    /// fails, it won't generate very useful error messages. The `parse_str`
+    /// function will eventually be deprecated in favor of `try_parse`.
+    ///
+    /// [`parse_str`]: #method.parse_str
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::NonNilUuid;
+    /// const UUID: Result<NonNilUuid, uuid::Error> =
+    ///     NonNilUuid::try_parse("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    ///
+    /// assert!(UUID.is_ok());
+    /// ```
+    pub const fn try_parse(input: &str) -> Result<Self, Error> {
+        match Uuid::try_parse(input) {
+            Ok(uuid) => match NonNilUuid::new(uuid) {
+                Some(uuid) => Ok(uuid),
+                None => Err(Error(ErrorKind::Nil)),
+            },
+            Err(err) => Err(err),
+        }
     }
 
     /// Get the underlying [`Uuid`] value.
     #[inline]
     pub const fn get(self) -> Uuid {
-        Uuid::from_u128(self.0.get())
+        Uuid::from_bytes(self.0.get().to_ne_bytes())
+    }
+
+    /// Get a reference to the underlying [`Uuid`] value.
+    #[inline]
+    pub const fn as_uuid(&self) -> &Uuid {
+        // SAFETY: `NonNilUuid` and `Uuid` have the same size, and `Uuid`
+        // has an alignment of 1. The bytes of the value are stored in the
+        // same order as `Uuid`
+        unsafe { &*(self as *const NonNilUuid as *const Uuid) }
+    }
+
+    /// Get a [`Hyphenated`] formatter.
+    #[inline]
+    pub const fn hyphenated(self) -> Hyphenated {
+        self.get().hyphenated()
+    }
+
+    /// Get a [`Simple`] formatter.
+    #[inline]
+    pub const fn simple(self) -> Simple {
+        self.get().simple()
+    }
+
+    /// Get a [`Urn`] formatter.
+    #[inline]
+    pub const fn urn(self) -> Urn {
+        self.get().urn()
+    }
+
+    /// Get a [`Braced`] formatter.
+    #[inline]
+    pub const fn braced(self) -> Braced {
+        self.get().braced()
+    }
+}
+
+// Constructors for versions of UUID that are never nil
+impl NonNilUuid {
+    /// Create a new version 1 UUID using the given timestamp and node ID.
+    ///
+    /// See [`Uuid::new_v1`] for more details.
+    #[cfg(feature = "v1")]
+    pub fn new_v1(ts: crate::Timestamp, node_id: &[u8; 6]) -> Self {
+        crate::UuidV1::new(ts, node_id).into()
+    }
+
+    /// Create a new version 1 UUID using the current system time and node ID.
+    ///
+    /// See [`Uuid::now_v1`] for more details.
+    #[cfg(all(feature = "v1", feature = "std", feature = "rng"))]
+    pub fn now_v1(node_id: &[u8; 6]) -> Self {
+        crate::UuidV1::now(node_id).into()
+    }
+
+    /// Create a new version 3 UUID using an MD5 hash of the given namespace and name.
+    ///
+    /// See [`Uuid::new_v3`] for more details.
+    #[cfg(feature = "v3")]
+    pub fn new_v3(namespace: &Uuid, name: &[u8]) -> Self {
+        crate::UuidV3::new(namespace, name).into()
+    }
+
+    /// Create a new random version 4 UUID.
+    ///
+    /// See [`Uuid::new_v4`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{NonNilUuid, Version};
+    /// let uuid = NonNilUuid::new_v4();
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get().get_version());
+    /// ```
+    #[cfg(feature = "v4")]
+    pub fn new_v4() -> Self {
+        crate::UuidV4::new().into()
+    }
+
+    /// Create a new version 5 UUID using a SHA1 hash of the given namespace and name.
+    ///
+    /// See [`Uuid::new_v5`] for more details.
+    #[cfg(feature = "v5")]
+    pub fn new_v5(namespace: &Uuid, name: &[u8]) -> Self {
+        crate::UuidV5::new(namespace, name).into()
+    }
+
+    /// Create a new version 6 UUID using the given timestamp and node ID.
+    ///
+    /// See [`Uuid::new_v6`] for more details.
+    #[cfg(feature = "v6")]
+    pub fn new_v6(ts: crate::Timestamp, node_id: &[u8; 6]) -> Self {
+        crate::UuidV6::new(ts, node_id).into()
+    }
+
+    /// Create a new version 6 UUID using the current system time and node ID.
+    ///
+    /// See [`Uuid::now_v6`] for more details.
+    #[cfg(all(feature = "v6", feature = "std", feature = "rng"))]
+    pub fn now_v6(node_id: &[u8; 6]) -> Self {
+        crate::UuidV6::now(node_id).into()
+    }
+
+    /// Create a new version 7 UUID using the given timestamp.
+    ///
+    /// See [`Uuid::new_v7`] for more details.
+    #[cfg(feature = "v7")]
+    pub fn new_v7(ts: crate::Timestamp) -> Self {
+        crate::UuidV7::new(ts).into()
+    }
+
+    /// Create a new version 7 UUID using the current system time.
+    ///
+    /// See [`Uuid::now_v7`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{NonNilUuid, Version};
+    /// let uuid = NonNilUuid::now_v7();
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get().get_version());
+    /// ```
+    #[cfg(all(feature = "v7", feature = "std"))]
+    pub fn now_v7() -> Self {
+        crate::UuidV7::now().into()
+    }
+
+    /// Create a new version 8 UUID using the given buffer.
+    ///
+    /// See [`Uuid::new_v8`] for more details.
+    #[cfg(feature = "v8")]
+    pub const fn new_v8(buf: [u8; 16]) -> Self {
+        // SAFETY: A version 8 UUID is never nil
+        unsafe { NonNilUuid::new_unchecked(Uuid::new_v8(buf)) }
     }
 }
 
@@ -109,7 +319,14 @@ impl From<NonNilUuid> for Uuid {
     /// assert_eq!(uuid, uuid_again);
     /// ```
     fn from(non_nil: NonNilUuid) -> Self {
-        Uuid::from_u128(non_nil.0.get())
+        non_nil.get()
+    }
+}
+
+impl AsRef<Uuid> for NonNilUuid {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        self.as_uuid()
     }
 }
 
@@ -125,9 +342,15 @@ impl TryFrom<Uuid> for NonNilUuid {
     /// let non_nil = NonNilUuid::try_from(uuid).unwrap();
     /// ```
     fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
-        NonZeroU128::new(uuid.as_u128())
-            .map(Self)
-            .ok_or(Error(ErrorKind::Nil))
+        NonNilUuid::new(uuid).ok_or(Error(ErrorKind::Nil))
+    }
+}
+
+impl FromStr for NonNilUuid {
+    type Err = Error;
+
+    fn from_str(uuid_str: &str) -> Result<Self, Self::Err> {
+        NonNilUuid::parse_str(uuid_str)
     }
 }
 
@@ -164,6 +387,68 @@ mod tests {
         assert_eq!(format!("{uuid:?}"), format!("{non_nil:?}"));
     }
 
+    #[test]
+    fn test_non_nil_parse() {
+        let uuid = Uuid::from_u128(0x0123456789abcdef0123456789abcdef);
+
+        assert_eq!(
+            uuid,
+            "01234567-89ab-cdef-0123-456789abcdef"
+                .parse::<NonNilUuid>()
+                .unwrap()
+        );
+        assert_eq!(
+            uuid,
+            NonNilUuid::try_parse("0123456789abcdef0123456789abcdef").unwrap()
+        );
+
+        assert_eq!(
+            Error(ErrorKind::Nil),
+            NonNilUuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap_err()
+        );
+        assert_eq!(
+            Error(ErrorKind::Nil),
+            NonNilUuid::try_parse("00000000-0000-0000-0000-000000000000").unwrap_err()
+        );
+        assert!(NonNilUuid::parse_str("not a uuid").is_err());
+    }
+
+    #[test]
+    fn test_non_nil_adapters() {
+        let uuid = Uuid::from_u128(0x0123456789abcdef0123456789abcdef);
+        let non_nil = NonNilUuid::new(uuid).unwrap();
+
+        assert_eq!(uuid.hyphenated(), non_nil.hyphenated());
+        assert_eq!(uuid.simple(), non_nil.simple());
+        assert_eq!(uuid.urn(), non_nil.urn());
+        assert_eq!(uuid.braced(), non_nil.braced());
+
+        assert_eq!(format!("{uuid:X}"), format!("{non_nil:X}"));
+        assert_eq!(&uuid, non_nil.as_uuid());
+    }
+
+    #[test]
+    fn test_non_nil_macro() {
+        const UUID: NonNilUuid = crate::non_nil_uuid!("01234567-89ab-cdef-0123-456789abcdef");
+
+        assert_eq!(Uuid::from_u128(0x0123456789abcdef0123456789abcdef), UUID);
+    }
+
+    #[test]
+    #[cfg(all(feature = "v4", feature = "v7", feature = "std"))]
+    fn test_non_nil_constructors() {
+        use crate::Version;
+
+        assert_eq!(
+            Some(Version::Random),
+            NonNilUuid::new_v4().get().get_version()
+        );
+        assert_eq!(
+            Some(Version::SortRand),
+            NonNilUuid::now_v7().get().get_version()
+        );
+    }
+
     #[test]
     fn test_non_nil_ord() {
         let uuid1 = Uuid::from_u128(0x0123456789abcdef0123456789abcdef);
@@ -265,7 +265,7 @@ pub(crate) const fn parse_hyphenated(s: &'_ [u8]) -> Result<[u8; 16], InvalidUui
     Ok(buf)
 }
 
-const HEX_TABLE: &[u8; 256] = &{
+pub(crate) const HEX_TABLE: &[u8; 256] = &{
     let mut buf = [0; 256];
     let mut i: u8 = 0;
 
@@ -0,0 +1,484 @@
+//! Compatibility with SQL Server's `uniqueidentifier` type.
+//!
+//! SQL Server doesn't order `uniqueidentifier` values by their bytes. It compares the
+//! last six bytes first, then works backwards through the remaining groups. That means
+//! UUIDs that are sortable by their bytes, like version 7 UUIDs, will fragment a
+//! clustered index in SQL Server. Sequential UUIDs for SQL Server need their timestamp
+//! in the trailing bytes instead.
+//!
+//! # References
+//!
+//! * [Comparing GUID and uniqueidentifier values](https://learn.microsoft.com/en-us/sql/connect/ado-net/sql/compare-guid-uniqueidentifier-values)
+//! * [`NEWSEQUENTIALID`](https://learn.microsoft.com/en-us/sql/t-sql/functions/newsequentialid-transact-sql)
+
+use core::{cmp::Ordering, fmt};
+
+use crate::{Builder, Timestamp, Uuid, Variant, Version};
+
+// The order SQL Server compares the bytes of a `uniqueidentifier` in.
+// These are indexes into the big-endian bytes of a `Uuid`.
+const SORT_ORDER: [usize; 16] = [10, 11, 12, 13, 14, 15, 8, 9, 7, 6, 5, 4, 3, 2, 1, 0];
+
+impl Uuid {
+    /// Compare two UUIDs the same way SQL Server compares `uniqueidentifier` values.
+    ///
+    /// SQL Server compares the node bytes first, then the clock sequence, and then the
+    /// time fields from least to most significant. This is different from the ordering of
+    /// [`Uuid`] itself, which compares raw bytes. Use [`SqlServerOrdered`] to store UUIDs
+    /// in a collection using this ordering.
+    ///
+    /// # Examples
+    ///
+    /// Sorting UUIDs the same way SQL Server does:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let mut uuids = [
+    ///     Uuid::parse_str("00000000-0000-0000-0000-000000000001")?,
+    ///     Uuid::parse_str("01000000-0000-0000-0000-000000000000")?,
+    ///     Uuid::parse_str("00000000-0000-0001-0000-000000000000")?,
+    /// ];
+    ///
+    /// uuids.sort_by(Uuid::sql_server_cmp);
+    ///
+    /// assert_eq!(
+    ///     [
+    ///         "01000000-0000-0000-0000-000000000000",
+    ///         "00000000-0000-0001-0000-000000000000",
+    ///         "00000000-0000-0000-0000-000000000001",
+    ///     ],
+    ///     uuids.map(|uuid| uuid.to_string()),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sql_server_cmp(&self, other: &Uuid) -> Ordering {
+        sort_key(self).cmp(&sort_key(other))
+    }
+
+    /// Create a new SQL Server sequential UUID using a time value and random bytes.
+    ///
+    /// The returned UUID is a version 8 UUID with a 48-bit Unix timestamp in milliseconds
+    /// stored in its last six bytes. Values created later will sort after values created
+    /// earlier using [`Uuid::sql_server_cmp`], so they can be used as clustered index keys
+    /// in SQL Server the same way `NEWSEQUENTIALID` values are. See
+    /// [`Builder::from_sql_server_timestamp_millis`] for the full layout.
+    ///
+    /// This method accepts a [`Timestamp`] which may include a counter value, like the one
+    /// produced by [`ContextV7`]. Only up to 74 bits of the counter value will be used when
+    /// constructing the UUID. Any unused counter bits will be filled with random data.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, ContextV7};
+    /// let context = ContextV7::new();
+    ///
+    /// let a = Uuid::new_sql_server_sequential(Timestamp::from_unix(&context, 1497624119, 1234));
+    /// let b = Uuid::new_sql_server_sequential(Timestamp::from_unix(&context, 1497624119, 1234));
+    ///
+    /// assert!(a.to_string().ends_with("-015cb15a86d8"));
+    /// assert_eq!(std::cmp::Ordering::Less, a.sql_server_cmp(&b));
+    /// ```
+    ///
+    /// [`ContextV7`]: struct.ContextV7.html
+    #[cfg(feature = "v7")]
+    pub fn new_sql_server_sequential(ts: Timestamp) -> Uuid {
+        let millis = ts.unix_millis();
+
+        let (counter, counter_bits) = ts.counter();
+
+        // The counter is stored in the most significant of the 74 available bits
+        // Any remaining bits are random
+        let counter_and_random = match counter_bits as u32 {
+            // The counter doesn't contribute any bits
+            0 => crate::rng::u128() >> 54,
+            // The counter needs to be merged with random data
+            counter_bits @ ..74 => {
+                let random = (crate::rng::u128() >> 54) & (u128::MAX >> (128 - 74 + counter_bits));
+                let counter =
+                    (counter & (u128::MAX >> (128 - counter_bits))) << (74 - counter_bits);
+
+                counter | random
+            }
+            // The counter overrides all bits
+            _ => counter,
+        };
+
+        let counter_random_bytes = [
+            (counter_and_random >> 68) as u8,
+            (counter_and_random >> 60) as u8,
+            (counter_and_random >> 52) as u8,
+            (counter_and_random >> 48) as u8,
+            (counter_and_random >> 40) as u8,
+            (counter_and_random >> 32) as u8,
+            (counter_and_random >> 24) as u8,
+            (counter_and_random >> 16) as u8,
+            (counter_and_random >> 8) as u8,
+            counter_and_random as u8,
+        ];
+
+        Builder::from_sql_server_timestamp_millis(millis, &counter_random_bytes).into_uuid()
+    }
+
+    /// Create a new SQL Server sequential UUID using the current system time.
+    ///
+    /// This method is a convenient alternative to [`Uuid::new_sql_server_sequential`] that
+    /// uses the current system time and a shared [`ContextV7`] to guarantee values created
+    /// by this process are ordered.
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::now_sql_server_sequential();
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert!(uuid.get_sql_server_timestamp().is_some());
+    /// ```
+    ///
+    /// [`ContextV7`]: struct.ContextV7.html
+    #[cfg(all(feature = "v7", feature = "std"))]
+    pub fn now_sql_server_sequential() -> Uuid {
+        Self::new_sql_server_sequential(Timestamp::now(
+            crate::timestamp::context::shared_context_v7(),
+        ))
+    }
+
+    /// Get the timestamp embedded in a SQL Server sequential UUID.
+    ///
+    /// This reverses [`Uuid::new_sql_server_sequential`] and
+    /// [`Builder::from_sql_server_timestamp_millis`]. The timestamp has millisecond precision.
+    ///
+    /// Any RFC 9562 version 8 UUID is accepted, since its layout is otherwise
+    /// unspecified. For other versions and variants this will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Uuid};
+    /// let uuid = Builder::from_sql_server_timestamp_millis(1497624119000, &[0; 10]).into_uuid();
+    ///
+    /// assert_eq!(
+    ///     Some((1497624119, 0)),
+    ///     uuid.get_sql_server_timestamp().map(|ts| ts.to_unix()),
+    /// );
+    /// ```
+    pub const fn get_sql_server_timestamp(&self) -> Option<Timestamp> {
+        match (self.get_version(), self.get_variant()) {
+            (Some(Version::Custom), Variant::RFC4122) => {
+                let bytes = self.as_bytes();
+
+                let millis = (bytes[10] as u64) << 40
+                    | (bytes[11] as u64) << 32
+                    | (bytes[12] as u64) << 24
+                    | (bytes[13] as u64) << 16
+                    | (bytes[14] as u64) << 8
+                    | (bytes[15] as u64);
+
+                let seconds = millis / 1000;
+                let nanos = ((millis % 1000) * 1_000_000) as u32;
+
+                Some(Timestamp::from_unix_time(seconds, nanos, 0, 0))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Builder {
+    /// Creates a `Builder` for a SQL Server sequential UUID using the supplied Unix timestamp
+    /// and counter bytes.
+    ///
+    /// The UUID is a version 8 UUID laid out so that SQL Server orders it by its timestamp,
+    /// and then by its counter bytes:
+    ///
+    /// * The 48-bit `millis` are stored big-endian in the last six bytes.
+    /// * The low 6 bits of `counter_random_bytes[0]` are stored after the variant.
+    /// * `counter_random_bytes[1]` and `counter_random_bytes[2]` fill the rest of the clock
+    ///   sequence and the high byte of the `time_hi_and_version` field.
+    /// * The low 4 bits of `counter_random_bytes[3]` are stored after the version.
+    /// * The remaining bytes are stored in reverse order in the first six bytes.
+    ///
+    /// The other bits of `counter_random_bytes` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Uuid, Variant, Version};
+    /// let uuid = Builder::from_sql_server_timestamp_millis(
+    ///     1497624119000,
+    ///     &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a],
+    /// )
+    /// .into_uuid();
+    ///
+    /// assert_eq!("0a090807-0605-8403-8102-015cb15a86d8", uuid.to_string());
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(Variant::RFC4122, uuid.get_variant());
+    /// ```
+    pub const fn from_sql_server_timestamp_millis(
+        millis: u64,
+        counter_random_bytes: &[u8; 10],
+    ) -> Self {
+        Builder::from_bytes([
+            counter_random_bytes[9],
+            counter_random_bytes[8],
+            counter_random_bytes[7],
+            counter_random_bytes[6],
+            counter_random_bytes[5],
+            counter_random_bytes[4],
+            (counter_random_bytes[3] & 0x0f) | 0x80,
+            counter_random_bytes[2],
+            (counter_random_bytes[0] & 0x3f) | 0x80,
+            counter_random_bytes[1],
+            (millis >> 40) as u8,
+            (millis >> 32) as u8,
+            (millis >> 24) as u8,
+            (millis >> 16) as u8,
+            (millis >> 8) as u8,
+            millis as u8,
+        ])
+    }
+}
+
+/// A [`Uuid`] that's ordered the same way SQL Server orders `uniqueidentifier` values.
+///
+/// This type can be used as a key in ordered collections like `BTreeMap` so they
+/// iterate in the same order as a SQL Server index. See [`Uuid::sql_server_cmp`]
+/// for details on the ordering.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{SqlServerOrdered, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let a = SqlServerOrdered::from_uuid(Uuid::parse_str("ffffffff-ffff-ffff-ffff-000000000000")?);
+/// let b = SqlServerOrdered::from_uuid(Uuid::parse_str("00000000-0000-0000-0000-000000000001")?);
+///
+/// assert!(a < b);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SqlServerOrdered(Uuid);
+
+impl SqlServerOrdered {
+    /// Creates a [`SqlServerOrdered`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        SqlServerOrdered(uuid)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`SqlServerOrdered`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Ord for SqlServerOrdered {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.sql_server_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for SqlServerOrdered {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for SqlServerOrdered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<Uuid> for SqlServerOrdered {
+    fn from(uuid: Uuid) -> Self {
+        SqlServerOrdered(uuid)
+    }
+}
+
+impl From<SqlServerOrdered> for Uuid {
+    fn from(ordered: SqlServerOrdered) -> Self {
+        ordered.0
+    }
+}
+
+impl AsRef<Uuid> for SqlServerOrdered {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+const fn sort_key(uuid: &Uuid) -> [u8; 16] {
+    let bytes = uuid.as_bytes();
+
+    let mut key = [0; 16];
+    let mut i = 0;
+
+    while i < 16 {
+        key[i] = bytes[SORT_ORDER[i]];
+        i += 1;
+    }
+
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::{collections::BTreeSet, string::ToString, vec::Vec};
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_cmp_byte_groups() {
+        // Each UUID sorts after the one before it in SQL Server
+        let ordered = [
+            "01000000-0000-0000-0000-000000000000",
+            "10000000-0000-0000-0000-000000000000",
+            "00010000-0000-0000-0000-000000000000",
+            "00100000-0000-0000-0000-000000000000",
+            "00000100-0000-0000-0000-000000000000",
+            "00001000-0000-0000-0000-000000000000",
+            "00000001-0000-0000-0000-000000000000",
+            "00000010-0000-0000-0000-000000000000",
+            "00000000-0100-0000-0000-000000000000",
+            "00000000-1000-0000-0000-000000000000",
+            "00000000-0001-0000-0000-000000000000",
+            "00000000-0010-0000-0000-000000000000",
+            "00000000-0000-0100-0000-000000000000",
+            "00000000-0000-1000-0000-000000000000",
+            "00000000-0000-0001-0000-000000000000",
+            "00000000-0000-0010-0000-000000000000",
+            "00000000-0000-0000-0001-000000000000",
+            "00000000-0000-0000-0010-000000000000",
+            "00000000-0000-0000-0100-000000000000",
+            "00000000-0000-0000-1000-000000000000",
+            "00000000-0000-0000-0000-000000000001",
+            "00000000-0000-0000-0000-000000000010",
+            "00000000-0000-0000-0000-000000000100",
+            "00000000-0000-0000-0000-000000001000",
+            "00000000-0000-0000-0000-000000010000",
+            "00000000-0000-0000-0000-000000100000",
+            "00000000-0000-0000-0000-000001000000",
+            "00000000-0000-0000-0000-000010000000",
+            "00000000-0000-0000-0000-000100000000",
+            "00000000-0000-0000-0000-001000000000",
+            "00000000-0000-0000-0000-010000000000",
+            "00000000-0000-0000-0000-100000000000",
+        ]
+        .map(|uuid| Uuid::parse_str(uuid).unwrap());
+
+        for pair in ordered.windows(2) {
+            assert_eq!(Ordering::Less, pair[0].sql_server_cmp(&pair[1]));
+            assert_eq!(Ordering::Greater, pair[1].sql_server_cmp(&pair[0]));
+        }
+
+        let set = ordered
+            .iter()
+            .rev()
+            .copied()
+            .map(SqlServerOrdered::from)
+            .collect::<BTreeSet<_>>();
+
+        assert_eq!(
+            &ordered[..],
+            &set.into_iter().map(Uuid::from).collect::<Vec<_>>()[..]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_from_timestamp_millis() {
+        let millis = 0x0123_4567_89ab;
+
+        let uuid = Builder::from_sql_server_timestamp_millis(millis, &[0xff; 10]).into_uuid();
+
+        assert_eq!(Some(Version::Custom), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!("ffffffff-ffff-8fff-bfff-0123456789ab", uuid.to_string());
+
+        let ts = uuid.get_sql_server_timestamp().unwrap();
+
+        assert_eq!((1_250_999_896, 491_000_000), ts.to_unix());
+
+        // Other versions don't have a SQL Server timestamp
+        assert_eq!(None, Uuid::nil().get_sql_server_timestamp());
+        assert_eq!(None, Uuid::max().get_sql_server_timestamp());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "v7")]
+    fn test_new_sequential() {
+        use crate::{ContextV7, NoContext};
+
+        let ts = Timestamp::from_unix(NoContext, 1_496_854_535, 812_946_000);
+
+        let uuid = Uuid::new_sql_server_sequential(ts);
+
+        assert_eq!(Some(Version::Custom), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(
+            (1_496_854_535, 812_000_000),
+            uuid.get_sql_server_timestamp().unwrap().to_unix()
+        );
+
+        // UUIDs created with the same context are ordered, even within the same millisecond
+        let context = ContextV7::new();
+
+        let mut uuids = Vec::new();
+        for _ in 0..10_000 {
+            uuids.push(Uuid::new_sql_server_sequential(Timestamp::from_unix(
+                &context,
+                1_496_854_535,
+                812_946_000,
+            )));
+        }
+
+        for pair in uuids.windows(2) {
+            assert_eq!(Ordering::Less, pair[0].sql_server_cmp(&pair[1]));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "v7")]
+    fn test_new_sequential_counter_overflow() {
+        // A counter wider than the available bits is truncated
+        let ts = Timestamp::from_unix_time(1_496_854_535, 0, u128::MAX, 128);
+
+        let uuid = Uuid::new_sql_server_sequential(ts);
+
+        assert_eq!("ffffffff-ffff-8fff-bfff-015c837b9b58", uuid.to_string());
+    }
+}
@@ -0,0 +1,257 @@
+//! Chronological ordering for time-based UUIDs.
+//!
+//! The derived ordering of [`Uuid`] compares raw bytes. That works for version 6 and 7
+//! UUIDs, whose timestamps are stored most significant bits first, but version 1 UUIDs
+//! store the least significant bits of their timestamp first. Versions 1, 6, and 7 also
+//! use different epochs and precisions, so their bytes can't be compared with each other.
+
+use core::{cmp::Ordering, fmt};
+
+use crate::{
+    timestamp::{self, UUID_TICKS_BETWEEN_EPOCHS},
+    Uuid,
+};
+
+impl Uuid {
+    /// Compare two UUIDs by the timestamps they embed.
+    ///
+    /// Version 1, 6, and 7 UUIDs are ordered by their decoded timestamp first, then by
+    /// their counter, then by their bytes. Version 7 timestamps are compared at millisecond
+    /// precision against the 100ns precision of versions 1 and 6. The counter is the clock
+    /// sequence in versions 1 and 6, and the 12 bits of `rand_a` in version 7, which hold
+    /// the most significant bits of the counter when one is used.
+    ///
+    /// UUIDs of any other version, including the nil and max UUIDs, sort after all
+    /// time-based UUIDs, and are ordered by their bytes. Use [`TimeOrdered`] to store
+    /// UUIDs in a collection using this ordering.
+    ///
+    /// # Examples
+    ///
+    /// Sorting version 1 and 7 UUIDs together:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let mut uuids = [
+    ///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+    ///     Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?,
+    ///     Uuid::parse_str("20616934-4ba2-11e7-8000-010203040506")?,
+    /// ];
+    ///
+    /// uuids.sort_by(Uuid::time_cmp);
+    ///
+    /// assert_eq!(
+    ///     [
+    ///         // 2017-06-07, version 1
+    ///         "20616934-4ba2-11e7-8000-010203040506",
+    ///         // 2022-02-22, version 7
+    ///         "017f22e2-79b0-7cc3-98c4-dc0c0c07398f",
+    ///         // No timestamp, version 4
+    ///         "67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     ],
+    ///     uuids.map(|uuid| uuid.to_string()),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn time_cmp(&self, other: &Uuid) -> Ordering {
+        sort_key(self)
+            .cmp(&sort_key(other))
+            .then_with(|| self.cmp(other))
+    }
+}
+
+/// A [`Uuid`] that's ordered by the timestamp it embeds.
+///
+/// This type can be used as a key in ordered collections like `BTreeMap` so version 1,
+/// 6, and 7 UUIDs iterate in chronological order. See [`Uuid::time_cmp`] for details
+/// on the ordering.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{TimeOrdered, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// // The raw bytes of the first UUID sort after the second
+/// let a = TimeOrdered::from_uuid(Uuid::parse_str("ffffffff-4ba2-11e7-8000-010203040506")?);
+/// let b = TimeOrdered::from_uuid(Uuid::parse_str("00000000-4ba3-11e7-8000-010203040506")?);
+///
+/// assert!(a < b);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct TimeOrdered(Uuid);
+
+impl TimeOrdered {
+    /// Creates a [`TimeOrdered`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        TimeOrdered(uuid)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`TimeOrdered`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Ord for TimeOrdered {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.time_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for TimeOrdered {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for TimeOrdered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<Uuid> for TimeOrdered {
+    fn from(uuid: Uuid) -> Self {
+        TimeOrdered(uuid)
+    }
+}
+
+impl From<TimeOrdered> for Uuid {
+    fn from(ordered: TimeOrdered) -> Self {
+        ordered.0
+    }
+}
+
+impl AsRef<Uuid> for TimeOrdered {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+// Time-based UUIDs are keyed by whether they're untimed (so they sort last),
+// their timestamp in 100ns ticks since the Gregorian epoch, and their counter
+const fn sort_key(uuid: &Uuid) -> (bool, u64, u16) {
+    match uuid.get_version_num() {
+        1 => {
+            let (ticks, counter) = timestamp::decode_gregorian_timestamp(uuid);
+
+            (false, ticks, counter)
+        }
+        6 => {
+            let (ticks, counter) = timestamp::decode_sorted_gregorian_timestamp(uuid);
+
+            (false, ticks, counter)
+        }
+        7 => {
+            let millis = timestamp::decode_unix_timestamp_millis(uuid);
+            let bytes = uuid.as_bytes();
+
+            (
+                false,
+                UUID_TICKS_BETWEEN_EPOCHS + millis * 10_000,
+                ((bytes[6] & 0x0F) as u16) << 8 | bytes[7] as u16,
+            )
+        }
+        _ => (true, 0, 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        std::{collections::BTreeSet, vec::Vec},
+        Builder,
+    };
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    // 2017-06-07T16:55:35.812946Z
+    const UNIX_SECONDS: u64 = 1_496_854_535;
+    const GREGORIAN_TICKS: u64 = UUID_TICKS_BETWEEN_EPOCHS + UNIX_SECONDS * 10_000_000 + 8_129_460;
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_mixed_versions() {
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let ordered = [
+            // The start of the millisecond
+            Builder::from_unix_timestamp_millis(UNIX_SECONDS * 1000 + 812, &[0; 10]).into_uuid(),
+            Builder::from_gregorian_timestamp(GREGORIAN_TICKS, 1, &node).into_uuid(),
+            Builder::from_sorted_gregorian_timestamp(GREGORIAN_TICKS, 2, &node).into_uuid(),
+            Builder::from_gregorian_timestamp(GREGORIAN_TICKS + 1, 0, &node).into_uuid(),
+            Builder::from_sorted_gregorian_timestamp(GREGORIAN_TICKS + 2, 0, &node).into_uuid(),
+            // The next millisecond
+            Builder::from_unix_timestamp_millis(UNIX_SECONDS * 1000 + 813, &[0; 10]).into_uuid(),
+            Builder::from_unix_timestamp_millis(
+                UNIX_SECONDS * 1000 + 813,
+                &[0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            )
+            .into_uuid(),
+            // Untimed UUIDs, by their bytes
+            Uuid::nil(),
+            Builder::from_random_bytes([0x42; 16]).into_uuid(),
+            Uuid::max(),
+        ];
+
+        for pair in ordered.windows(2) {
+            assert_eq!(Ordering::Less, pair[0].time_cmp(&pair[1]));
+            assert_eq!(Ordering::Greater, pair[1].time_cmp(&pair[0]));
+        }
+
+        let set = ordered
+            .iter()
+            .rev()
+            .copied()
+            .map(TimeOrdered::from)
+            .collect::<BTreeSet<_>>();
+
+        assert_eq!(
+            &ordered[..],
+            &set.into_iter().map(Uuid::from).collect::<Vec<_>>()[..]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_v1_low_bits_first() {
+        let node = [1, 2, 3, 4, 5, 6];
+
+        // The low 32 bits of the timestamp are stored first, so this wraps in raw byte order
+        let a = Builder::from_gregorian_timestamp(0x0123_4567_ffff_ffff, 0, &node).into_uuid();
+        let b = Builder::from_gregorian_timestamp(0x0123_4568_0000_0000, 0, &node).into_uuid();
+
+        assert!(a > b);
+        assert_eq!(Ordering::Less, a.time_cmp(&b));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_equal() {
+        let uuid = Uuid::parse_str("20616934-4ba2-11e7-8000-010203040506").unwrap();
+
+        assert_eq!(Ordering::Equal, uuid.time_cmp(&uuid));
+        assert_eq!(TimeOrdered::from(uuid), TimeOrdered::from_uuid(uuid));
+    }
+}
@@ -29,6 +29,12 @@ use crate::Uuid;
 /// (`1582-10-15 00:00:00`) and the Unix epoch (`1970-01-01 00:00:00`).
 pub const UUID_TICKS_BETWEEN_EPOCHS: u64 = 0x01B2_1DD2_1381_4000;
 
+// The number of seconds between the Unix epoch and the NCS epoch (`1980-01-01 00:00:00`)
+const NCS_SECONDS_BETWEEN_EPOCHS: u64 = 315_532_800;
+
+// The number of 4 microsecond ticks in a second, as used by NCS variant UUIDs
+const NCS_TICKS_PER_SECOND: u64 = 250_000;
+
 /// A timestamp that can be encoded into a UUID.
 ///
 /// This type abstracts the specific encoding, so versions 1, 6, and 7
@@ -149,6 +155,44 @@ impl Timestamp {
         (self.seconds, self.subsec_nanos)
     }
 
+    // NOTE: The number of milliseconds since the Unix epoch saturates at `u64::MAX`
+    // instead of wrapping. Formats that store fewer bits need to truncate it themselves.
+    pub(crate) const fn unix_millis(&self) -> u64 {
+        self.seconds
+            .saturating_mul(1000)
+            .saturating_add(self.subsec_nanos as u64 / 1_000_000)
+    }
+
+    /// Construct a `Timestamp` from the number of 4 microsecond ticks since 00:00:00.00,
+    /// 1 January 1980, as used in legacy NCS variant UUIDs.
+    ///
+    /// # Overflow
+    ///
+    /// If conversion from NCS ticks to the internal timestamp format would overflow
+    /// it will wrap.
+    pub const fn from_ncs_time(ticks: u64) -> Self {
+        Timestamp {
+            seconds: NCS_SECONDS_BETWEEN_EPOCHS.wrapping_add(ticks / NCS_TICKS_PER_SECOND),
+            subsec_nanos: (ticks % NCS_TICKS_PER_SECOND) as u32 * 4_000,
+            counter: 0,
+            usable_counter_bits: 0,
+        }
+    }
+
+    /// Get the value of the timestamp as the number of 4 microsecond ticks since 00:00:00.00,
+    /// 1 January 1980, as used in legacy NCS variant UUIDs.
+    ///
+    /// # Overflow
+    ///
+    /// If the timestamp is earlier than 1980, or conversion from the internal timestamp
+    /// format to ticks would overflow, then it will wrap.
+    pub const fn to_ncs(&self) -> u64 {
+        self.seconds
+            .wrapping_sub(NCS_SECONDS_BETWEEN_EPOCHS)
+            .wrapping_mul(NCS_TICKS_PER_SECOND)
+            .wrapping_add(self.subsec_nanos as u64 / 4_000)
+    }
+
     const fn unix_to_gregorian_ticks(seconds: u64, nanos: u32) -> u64 {
         UUID_TICKS_BETWEEN_EPOCHS
             .wrapping_add(seconds.wrapping_mul(10_000_000))
@@ -333,6 +377,38 @@ pub(crate) const fn encode_unix_timestamp_millis(
     Uuid::from_fields(millis_high, millis_low, counter_random_version, &d4)
 }
 
+pub(crate) const fn encode_ncs_timestamp(ticks: u64, family: u8, host: &[u8; 7]) -> Uuid {
+    Uuid::from_bytes([
+        (ticks >> 40) as u8,
+        (ticks >> 32) as u8,
+        (ticks >> 24) as u8,
+        (ticks >> 16) as u8,
+        (ticks >> 8) as u8,
+        ticks as u8,
+        0,
+        0,
+        family & 0x7F,
+        host[0],
+        host[1],
+        host[2],
+        host[3],
+        host[4],
+        host[5],
+        host[6],
+    ])
+}
+
+pub(crate) const fn decode_ncs_timestamp(uuid: &Uuid) -> u64 {
+    let bytes = uuid.as_bytes();
+
+    (bytes[0] as u64) << 40
+        | (bytes[1] as u64) << 32
+        | (bytes[2] as u64) << 24
+        | (bytes[3] as u64) << 16
+        | (bytes[4] as u64) << 8
+        | (bytes[5] as u64)
+}
+
 pub(crate) const fn decode_unix_timestamp_millis(uuid: &Uuid) -> u64 {
     let bytes = uuid.as_bytes();
 
@@ -719,12 +795,10 @@ pub mod context {
 
         const USABLE_BITS: usize = 42;
 
-        // Leave the most significant bit unset
-        // This guarantees the counter has at least 2,199,023,255,552
-        // values before it will overflow, which is exceptionally unlikely
-        // even in the worst case
-        const RESEED_MASK: u64 = u64::MAX >> 23;
-        const MAX_COUNTER: u64 = u64::MAX >> 22;
+        // In monotonic random mode the counter replaces all 74 random bits
+        // of the UUID, but only the least significant 63 of them are set
+        const MONOTONIC_RANDOM_USABLE_BITS: usize = 74;
+        const MONOTONIC_RANDOM_COUNTER_BITS: usize = 63;
 
         /// An unsynchronized, reseeding counter that produces 42-bit values for version 7 UUIDs.
         ///
@@ -748,6 +822,10 @@ pub mod context {
         /// version 7 UUID, the 42-bit counter will be padded with random data. This type can
         /// be used to maintain ordering of UUIDs within the same millisecond.
         ///
+        /// The above describes the default mode. A context created with
+        /// [`ContextV7::with_monotonic_random`] instead produces a 63-bit counter that fills all 74
+        /// random bits of the UUID, and ignores any additional precision.
+        ///
         /// This type should not be used when constructing version 1 or version 6 UUIDs.
         /// When used to construct a version 1 or version 6 UUID, only the 14 least significant
         /// bits of the counter will be used.
@@ -757,6 +835,7 @@ pub mod context {
             counter: Cell<Counter>,
             adjust: Adjust,
             precision: Precision,
+            monotonic_random: bool,
         }
 
         impl RefUnwindSafe for ContextV7 {}
@@ -779,6 +858,7 @@ pub mod context {
                         factor: 0,
                         shift: 0,
                     },
+                    monotonic_random: false,
                 }
             }
 
@@ -798,6 +878,61 @@ pub mod context {
                 self.precision = Precision::new(12);
                 self
             }
+
+            /// Generate the random part of UUIDs monotonically, as described by the ULID spec.
+            ///
+            /// In this mode the counter replaces all 74 random bits of a version 7 UUID. It's
+            /// reseeded with a random 62-bit value on each new millisecond, and incremented by 1
+            /// for each UUID generated within the same millisecond, so the random part of
+            /// consecutive UUIDs only differs in its least significant bits. When formatted
+            /// with [`Uuid::to_ulid_string`](crate::Uuid::to_ulid_string) these UUIDs sort the
+            /// same way as monotonic ULIDs.
+            ///
+            /// Additional precision set by [`ContextV7::with_additional_precision`] isn't used
+            /// in this mode.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use uuid::{ContextV7, Timestamp, Uuid};
+            /// let context = ContextV7::new().with_monotonic_random();
+            ///
+            /// let uuid1 = Uuid::new_v7(Timestamp::from_unix(&context, 1_497_624_119, 1_234));
+            /// let uuid2 = Uuid::new_v7(Timestamp::from_unix(&context, 1_497_624_119, 1_234));
+            ///
+            /// assert_eq!(uuid1.as_u128() + 1, uuid2.as_u128());
+            /// ```
+            ///
+            /// # References
+            ///
+            /// * [Monotonicity in the ULID spec](https://github.com/ulid/spec#monotonicity)
+            pub fn with_monotonic_random(mut self) -> Self {
+                self.monotonic_random = true;
+                self
+            }
+
+            fn precision(&self) -> &Precision {
+                const NO_PRECISION: Precision = Precision {
+                    bits: 0,
+                    mask: 0,
+                    factor: 0,
+                    shift: 0,
+                };
+
+                if self.monotonic_random {
+                    &NO_PRECISION
+                } else {
+                    &self.precision
+                }
+            }
+
+            fn counter_bits(&self) -> usize {
+                if self.monotonic_random {
+                    MONOTONIC_RANDOM_COUNTER_BITS
+                } else {
+                    USABLE_BITS
+                }
+            }
         }
 
         impl ClockSequence for ContextV7 {
@@ -818,9 +953,12 @@ pub mod context {
                 let (mut timestamp, should_reseed) =
                     self.timestamp.get().advance(seconds, subsec_nanos);
 
+                let precision = self.precision();
+                let counter_bits = self.counter_bits();
+
                 if should_reseed {
                     // If the observed system time has shifted forwards then regenerate the counter
-                    counter = Counter::reseed(&self.precision, &timestamp);
+                    counter = Counter::reseed(precision, counter_bits, &timestamp);
                 } else {
                     // If the observed system time has not shifted forwards then increment the counter
 
@@ -828,15 +966,15 @@ pub mod context {
                     // use it instead. This may happen if the system clock jitters, or if the counter
                     // has wrapped and the timestamp is artificially incremented
 
-                    counter = self.counter.get().increment(&self.precision, &timestamp);
+                    counter = self.counter.get().increment(precision, &timestamp);
 
                     // Unlikely: If the counter has overflowed its 42-bit storage then wrap it
                     // and increment the timestamp. Until the observed system time shifts past
                     // this incremented value, all timestamps will use it to maintain monotonicity
-                    if counter.has_overflowed() {
+                    if counter.has_overflowed(counter_bits) {
                         // Increment the timestamp by 1 milli and reseed the counter
                         timestamp = timestamp.increment();
-                        counter = Counter::reseed(&self.precision, &timestamp);
+                        counter = Counter::reseed(precision, counter_bits, &timestamp);
                     }
                 };
 
@@ -847,7 +985,11 @@ pub mod context {
             }
 
             fn usable_bits(&self) -> usize {
-                USABLE_BITS
+                if self.monotonic_random {
+                    MONOTONIC_RANDOM_USABLE_BITS
+                } else {
+                    USABLE_BITS
+                }
             }
         }
 
@@ -915,9 +1057,15 @@ pub mod context {
 
         impl Counter {
             #[inline]
-            fn reseed(precision: &Precision, timestamp: &ReseedingTimestamp) -> Self {
+            fn reseed(precision: &Precision, bits: usize, timestamp: &ReseedingTimestamp) -> Self {
+                // Leave the most significant bit unset
+                // For the default 42-bit counter this guarantees at least 2,199,023,255,552
+                // values before it will overflow, which is exceptionally unlikely
+                // even in the worst case
+                let reseed_mask = u64::MAX >> (65 - bits);
+
                 Counter {
-                    value: precision.apply(crate::rng::u64() & RESEED_MASK, timestamp),
+                    value: precision.apply(crate::rng::u64() & reseed_mask, timestamp),
                 }
             }
 
@@ -940,8 +1088,8 @@ pub mod context {
             }
 
             #[inline]
-            fn has_overflowed(&self) -> bool {
-                self.value > MAX_COUNTER
+            fn has_overflowed(&self, bits: usize) -> bool {
+                self.value > u64::MAX >> (64 - bits)
             }
         }
 
@@ -1102,6 +1250,7 @@ pub mod context {
                     counter: Cell::new(Counter {
                         value: u64::MAX >> 22,
                     }),
+                    monotonic_random: false,
                 };
 
                 let ts = Timestamp::from_unix(&context, seconds, subsec_nanos);
@@ -1165,10 +1314,66 @@ pub mod context {
                     ContextV7::new(),
                     ContextV7::new().with_additional_precision(),
                     ContextV7::new().with_adjust_by_millis(u32::MAX),
+                    ContextV7::new().with_monotonic_random(),
                 ] {
                     Timestamp::from_unix(&context, seconds, subsec_nanos);
                 }
             }
+
+            #[test]
+            fn context_monotonic_random() {
+                let seconds = 1_496_854_535;
+                let subsec_nanos = 812_946_000;
+
+                let context = ContextV7::new()
+                    .with_additional_precision()
+                    .with_monotonic_random();
+
+                let ts1 = Timestamp::from_unix(&context, seconds, subsec_nanos);
+                assert_eq!(74, ts1.usable_counter_bits);
+                assert!(ts1.counter <= (u64::MAX >> 2) as u128);
+
+                // The counter should increment by 1, even though the time has advanced
+                // within the millisecond
+                let ts2 = Timestamp::from_unix(&context, seconds, subsec_nanos + 1_000);
+                assert_eq!(ts1.counter + 1, ts2.counter);
+
+                let uuid1 = Uuid::new_v7(ts1);
+                let uuid2 = Uuid::new_v7(ts2);
+
+                assert_eq!(uuid1.as_u128() + 1, uuid2.as_u128());
+
+                // Forwards millisecond
+                let ts3 = Timestamp::from_unix(&context, seconds, subsec_nanos + 1_000_000);
+
+                assert_ne!(ts2.counter + 1, ts3.counter);
+                assert!(Uuid::new_v7(ts3) > uuid2);
+            }
+
+            #[test]
+            fn context_monotonic_random_wrap() {
+                let seconds = 1_496_854_535u64;
+                let subsec_nanos = 812_946_000u32;
+
+                // This context will wrap
+                let context = ContextV7::new().with_monotonic_random();
+                context
+                    .timestamp
+                    .set(ReseedingTimestamp::from_ts(seconds, subsec_nanos));
+                context.counter.set(Counter {
+                    value: u64::MAX >> 1,
+                });
+
+                let ts = Timestamp::from_unix(&context, seconds, subsec_nanos);
+
+                // The timestamp should be incremented by 1ms
+                let expected_ts = Duration::new(seconds, subsec_nanos) + Duration::from_millis(1);
+                assert_eq!(expected_ts.as_secs(), ts.seconds);
+                assert_eq!(expected_ts.subsec_nanos(), ts.subsec_nanos);
+
+                // The counter should have reseeded
+                assert!(ts.counter <= (u64::MAX >> 2) as u128);
+            }
         }
     }
 
@@ -0,0 +1,439 @@
+//! UUIDs tagged with the type of entity they identify.
+//!
+//! A [`TypedUuid<T>`] is a [`Uuid`] with a phantom type parameter. The type parameter
+//! stops an ID for one kind of entity being passed where another is expected, without
+//! having to write a newtype for each:
+//!
+//! ```compile_fail
+//! # use uuid::{TypedUuid, Uuid};
+//! struct User;
+//! struct Order;
+//!
+//! fn find_user(id: TypedUuid<User>) {}
+//!
+//! let order_id = TypedUuid::<Order>::from_uuid(Uuid::nil());
+//!
+//! find_user(order_id);
+//! ```
+//!
+//! The tag type is never constructed, so it can be any type, including unsized ones.
+//! A `TypedUuid<T>` formats, parses, compares, and serializes exactly like a `Uuid`.
+//! Tags can opt-in to a prefix for display purposes by implementing [`IdPrefix`].
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use crate::{
+    error::{Error, ErrorKind},
+    fmt::{Braced, Hyphenated, Simple, Urn},
+    std::borrow::Borrow,
+    Uuid,
+};
+
+/// A [`Uuid`] tagged with the type of entity it identifies.
+///
+/// See the [module-level documentation](index.html) for more details.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{TypedUuid, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// struct User;
+///
+/// type UserId = TypedUuid<User>;
+///
+/// let id: UserId = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse()?;
+///
+/// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", id.to_string());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # ABI
+///
+/// The `TypedUuid` type is always guaranteed to have the same ABI as [`Uuid`].
+#[repr(transparent)]
+pub struct TypedUuid<T: ?Sized> {
+    uuid: Uuid,
+    // `fn() -> T` keeps the tag from affecting auto traits like `Send` and `Sync`
+    _marker: PhantomData<fn() -> T>,
+}
+
+/// A prefix to display in front of [`TypedUuid`]s with a given tag.
+///
+/// The prefix is used by [`TypedUuid::prefixed`], and isn't added by any other
+/// formatting or serialization.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{typed::IdPrefix, TypedUuid, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// struct User;
+///
+/// impl IdPrefix for User {
+///     const PREFIX: &'static str = "user_";
+/// }
+///
+/// let id = TypedUuid::<User>::from_uuid(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?);
+///
+/// assert_eq!("user_67e55044-10b1-426f-9247-bb680e5fe0c8", id.prefixed().to_string());
+///
+/// let parsed: uuid::typed::Prefixed<User> = "user_67e55044-10b1-426f-9247-bb680e5fe0c8".parse()?;
+///
+/// assert_eq!(id, parsed.into_typed());
+/// # Ok(())
+/// # }
+/// ```
+pub trait IdPrefix {
+    /// The prefix, including any separator.
+    const PREFIX: &'static str;
+}
+
+/// Format a [`TypedUuid`] with the [`IdPrefix`] of its tag, like
+/// `user_67e55044-10b1-426f-9247-bb680e5fe0c8`.
+#[repr(transparent)]
+pub struct Prefixed<T: IdPrefix + ?Sized>(TypedUuid<T>);
+
+impl<T: ?Sized> TypedUuid<T> {
+    /// Tag a [`Uuid`].
+    #[inline]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        TypedUuid {
+            uuid,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The nil UUID with this tag.
+    #[inline]
+    pub const fn nil() -> Self {
+        TypedUuid::from_uuid(Uuid::nil())
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    #[inline]
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Remove the tag, returning the underlying [`Uuid`].
+    #[inline]
+    pub const fn untyped(self) -> Uuid {
+        self.uuid
+    }
+
+    /// Change the tag on this UUID.
+    ///
+    /// This is an escape hatch for cases where the same UUID identifies
+    /// more than one kind of entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{TypedUuid, Uuid};
+    /// struct User;
+    /// struct Account;
+    ///
+    /// let user_id = TypedUuid::<User>::from_uuid(Uuid::nil());
+    /// let account_id: TypedUuid<Account> = user_id.cast();
+    ///
+    /// assert_eq!(user_id.untyped(), account_id.untyped());
+    /// ```
+    #[inline]
+    pub const fn cast<U: ?Sized>(self) -> TypedUuid<U> {
+        TypedUuid::from_uuid(self.uuid)
+    }
+
+    /// Get a [`Hyphenated`] formatter.
+    #[inline]
+    pub const fn hyphenated(self) -> Hyphenated {
+        self.uuid.hyphenated()
+    }
+
+    /// Get a [`Simple`] formatter.
+    #[inline]
+    pub const fn simple(self) -> Simple {
+        self.uuid.simple()
+    }
+
+    /// Get a [`Urn`] formatter.
+    #[inline]
+    pub const fn urn(self) -> Urn {
+        self.uuid.urn()
+    }
+
+    /// Get a [`Braced`] formatter.
+    #[inline]
+    pub const fn braced(self) -> Braced {
+        self.uuid.braced()
+    }
+}
+
+impl<T: IdPrefix + ?Sized> TypedUuid<T> {
+    /// Get a [`Prefixed`] formatter.
+    #[inline]
+    pub const fn prefixed(self) -> Prefixed<T> {
+        Prefixed(self)
+    }
+}
+
+impl<T: IdPrefix + ?Sized> Prefixed<T> {
+    /// Get the underlying [`TypedUuid`].
+    #[inline]
+    pub const fn into_typed(self) -> TypedUuid<T> {
+        self.0
+    }
+}
+
+impl<T: ?Sized> Clone for TypedUuid<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for TypedUuid<T> {}
+
+impl<T: ?Sized> PartialEq for TypedUuid<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.uuid == other.uuid
+    }
+}
+
+impl<T: ?Sized> Eq for TypedUuid<T> {}
+
+impl<T: ?Sized> PartialOrd for TypedUuid<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized> Ord for TypedUuid<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.uuid.cmp(&other.uuid)
+    }
+}
+
+impl<T: ?Sized> PartialEq<Uuid> for TypedUuid<T> {
+    #[inline]
+    fn eq(&self, other: &Uuid) -> bool {
+        self.uuid == *other
+    }
+}
+
+impl<T: ?Sized> PartialEq<TypedUuid<T>> for Uuid {
+    #[inline]
+    fn eq(&self, other: &TypedUuid<T>) -> bool {
+        *self == other.uuid
+    }
+}
+
+impl<T: ?Sized> Hash for TypedUuid<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uuid.hash(state)
+    }
+}
+
+impl<T: ?Sized> Default for TypedUuid<T> {
+    #[inline]
+    fn default() -> Self {
+        TypedUuid::nil()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for TypedUuid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.uuid, f)
+    }
+}
+
+impl<T: ?Sized> fmt::Display for TypedUuid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.uuid, f)
+    }
+}
+
+impl<T: ?Sized> fmt::LowerHex for TypedUuid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.uuid, f)
+    }
+}
+
+impl<T: ?Sized> fmt::UpperHex for TypedUuid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.uuid, f)
+    }
+}
+
+impl<T: ?Sized> FromStr for TypedUuid<T> {
+    type Err = Error;
+
+    fn from_str(uuid_str: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(uuid_str).map(TypedUuid::from_uuid)
+    }
+}
+
+impl<T: ?Sized> From<Uuid> for TypedUuid<T> {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        TypedUuid::from_uuid(uuid)
+    }
+}
+
+impl<T: ?Sized> From<TypedUuid<T>> for Uuid {
+    #[inline]
+    fn from(typed: TypedUuid<T>) -> Self {
+        typed.uuid
+    }
+}
+
+impl<T: ?Sized> AsRef<Uuid> for TypedUuid<T> {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+impl<T: ?Sized> Borrow<Uuid> for TypedUuid<T> {
+    #[inline]
+    fn borrow(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+impl<T: IdPrefix + ?Sized> Clone for Prefixed<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: IdPrefix + ?Sized> Copy for Prefixed<T> {}
+
+impl<T: IdPrefix + ?Sized> fmt::Debug for Prefixed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T: IdPrefix + ?Sized> fmt::Display for Prefixed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", T::PREFIX, self.0)
+    }
+}
+
+impl<T: IdPrefix + ?Sized> FromStr for Prefixed<T> {
+    type Err = Error;
+
+    fn from_str(prefixed_str: &str) -> Result<Self, Self::Err> {
+        let uuid_str = prefixed_str
+            .strip_prefix(T::PREFIX)
+            .ok_or(Error(ErrorKind::ParseOther))?;
+
+        uuid_str.parse().map(Prefixed)
+    }
+}
+
+impl<T: IdPrefix + ?Sized> From<Prefixed<T>> for TypedUuid<T> {
+    #[inline]
+    fn from(prefixed: Prefixed<T>) -> Self {
+        prefixed.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        std::{collections::HashSet, string::ToString},
+        tests::some_uuid_v4,
+    };
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    struct User;
+
+    impl IdPrefix for User {
+        const PREFIX: &'static str = "user_";
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_typed_forwarding() {
+        let uuid = some_uuid_v4();
+        let typed = TypedUuid::<User>::from_uuid(uuid);
+
+        assert_eq!(uuid, typed);
+        assert_eq!(uuid, typed.untyped());
+        assert_eq!(uuid.to_string(), typed.to_string());
+        assert_eq!(format!("{:?}", uuid), format!("{:?}", typed));
+        assert_eq!(format!("{:X}", uuid), format!("{:X}", typed));
+        assert_eq!(uuid.simple().to_string(), typed.simple().to_string());
+        assert_eq!(uuid.urn().to_string(), typed.urn().to_string());
+        assert_eq!(uuid.braced().to_string(), typed.braced().to_string());
+
+        assert_eq!(typed, typed.to_string().parse::<TypedUuid<User>>().unwrap());
+        assert_eq!(TypedUuid::<User>::nil(), TypedUuid::default());
+
+        // Typed UUIDs can be looked up by their untyped value
+        let set = [typed].into_iter().collect::<HashSet<_>>();
+
+        assert!(set.contains(&uuid));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_typed_unsized_tag() {
+        let typed = TypedUuid::<dyn core::any::Any>::from_uuid(some_uuid_v4());
+        let cast: TypedUuid<str> = typed.cast();
+
+        assert_eq!(typed.untyped(), cast.untyped());
+        assert!(TypedUuid::<str>::nil() < cast);
+
+        assert_eq!(
+            core::mem::size_of::<Uuid>(),
+            core::mem::size_of::<TypedUuid<str>>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_typed_prefixed() {
+        let uuid_str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let typed = uuid_str.parse::<TypedUuid<User>>().unwrap();
+
+        assert_eq!(
+            "user_67e55044-10b1-426f-9247-bb680e5fe0c8",
+            typed.prefixed().to_string()
+        );
+
+        let parsed: Prefixed<User> = "user_67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+
+        assert_eq!(typed, parsed.into_typed());
+
+        assert!(uuid_str.parse::<Prefixed<User>>().is_err());
+        assert!("user_not-a-uuid".parse::<Prefixed<User>>().is_err());
+    }
+}
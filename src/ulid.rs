@@ -0,0 +1,230 @@
+//! Interoperability with ULIDs.
+//!
+//! A ULID is a 128-bit identifier made up of a 48-bit Unix timestamp in milliseconds
+//! followed by 80 random bits, formatted as 26 characters of Crockford base32. This is
+//! the same layout as a version 7 UUID, except that a version 7 UUID also stores its
+//! version and variant in 6 of those random bits.
+//!
+//! Any 128-bit value can be formatted as a ULID, so a [`Uuid`] can always be converted
+//! to and from its ULID string without loss. To use a ULID as a valid version 7 UUID,
+//! its version and variant bits need to be set with [`Uuid::from_ulid_v7`].
+//!
+//! # References
+//!
+//! * [The ULID spec](https://github.com/ulid/spec)
+
+use crate::{error::*, fmt::Base32, Uuid};
+
+// The version and variant bits of a version 7 UUID
+const V7_MASK: u128 = 0xF << 76 | 0b11 << 62;
+const V7_BITS: u128 = 0x7 << 76 | 0b10 << 62;
+
+impl Uuid {
+    /// Parses a ULID string into a UUID with the same bits.
+    ///
+    /// The input must be 26 characters of Crockford base32. Decoding is case-insensitive,
+    /// and `I` and `L` are read as `1`, and `O` as `0`, as the ULID spec recommends.
+    ///
+    /// The bits of the ULID are kept as-is, so the result won't generally be a valid
+    /// version 7 UUID. Use [`Uuid::from_ulid_v7`] to convert it into one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FAV")?;
+    ///
+    /// assert_eq!(
+    ///     Uuid::parse_str("01563e3a-b5d3-d676-4c61-efb99302bd5b")?,
+    ///     uuid,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_ulid_str(ulid: &str) -> Result<Uuid, Error> {
+        Base32::parse_str(ulid).map(Base32::into_uuid)
+    }
+
+    /// Format the UUID as a ULID string.
+    ///
+    /// The result is 26 characters of upper-case Crockford base32, which is the canonical
+    /// form of a ULID. The timestamp of a version 7 UUID is kept in the first 10 characters,
+    /// so ULID strings of version 7 UUIDs sort in the same order as the UUIDs themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("01563e3a-b5d3-7676-8c61-efb99302bd5b")?;
+    ///
+    /// assert_eq!("01ARZ3NDEKESV8RRFFQ69G5FAV", uuid.to_ulid_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_ulid_string(&self) -> crate::std::string::String {
+        let mut buffer = [0; Base32::LENGTH];
+
+        self.base32().encode_upper(&mut buffer).into()
+    }
+
+    /// Convert a ULID into a version 7 UUID.
+    ///
+    /// The timestamp of a ULID and a version 7 UUID are stored the same way, so it's kept
+    /// as-is. The version and variant of a version 7 UUID overlap 6 of the ULID's random bits,
+    /// so converting a ULID is lossy unless it happens to have those bits set already.
+    ///
+    /// If `lossless` is `false` then the version and variant bits are overwritten. If
+    /// `lossless` is `true` then an error is returned instead of overwriting them. This
+    /// can be used to check whether a ULID was originally formatted from a version 7 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let ulid = Uuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FAV")?.as_u128();
+    ///
+    /// let uuid = Uuid::from_ulid_v7(ulid, false)?;
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get_version());
+    /// assert_eq!("01563e3a-b5d3-7676-8c61-efb99302bd5b", uuid.hyphenated().to_string());
+    ///
+    /// // Some of the ULID's random bits were overwritten
+    /// assert!(Uuid::from_ulid_v7(ulid, true).is_err());
+    ///
+    /// // The UUID itself is a ULID that can be converted losslessly
+    /// assert_eq!(uuid, Uuid::from_ulid_v7(uuid.as_u128(), true)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn from_ulid_v7(ulid: u128, lossless: bool) -> Result<Uuid, Error> {
+        if lossless && ulid & V7_MASK != V7_BITS {
+            return Err(Error(ErrorKind::UlidNotV7));
+        }
+
+        Ok(Uuid::from_u128(ulid & !V7_MASK | V7_BITS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Variant, Version};
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "std")]
+    fn test_ulid_str_roundtrip() {
+        for ulid in [
+            "00000000000000000000000000",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAV",
+            "01HF7YAT00FAQRVVR14D2PF2DB",
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+        ] {
+            let uuid = Uuid::from_ulid_str(ulid).unwrap();
+
+            assert_eq!(ulid, uuid.to_ulid_string());
+            assert_eq!(uuid, Uuid::from_ulid_str(&ulid.to_lowercase()).unwrap());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_ulid_str_invalid() {
+        for ulid in [
+            "",
+            "01ARZ3NDEKTSV4RRFFQ69G5FA",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAVV",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAU",
+            "81ARZ3NDEKTSV4RRFFQ69G5FAV",
+            "01563e3a-b5d3-7676-8c61-efb99302bd5b",
+        ] {
+            assert!(Uuid::from_ulid_str(ulid).is_err(), "{ulid}");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_from_ulid_v7() {
+        let ulid = Uuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+
+        let uuid = Uuid::from_ulid_v7(ulid.as_u128(), false).unwrap();
+
+        assert_eq!(Some(Version::SortRand), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(
+            ulid.as_u128() & !V7_MASK,
+            uuid.as_u128() & !V7_MASK,
+            "only the version and variant bits should change"
+        );
+        assert_eq!(
+            Some(1_469_922_850_259),
+            uuid.get_timestamp().map(|ts| {
+                let (secs, nanos) = ts.to_unix();
+
+                secs * 1_000 + nanos as u64 / 1_000_000
+            })
+        );
+
+        assert_eq!(
+            Error(ErrorKind::UlidNotV7),
+            Uuid::from_ulid_v7(ulid.as_u128(), true).unwrap_err()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "std")]
+    fn test_from_ulid_v7_lossless() {
+        let uuid = Uuid::parse_str("018bcfe5-6800-7aaf-8def-0123456789ab").unwrap();
+
+        assert_eq!("01HF7YAT00FAQRVVR14D2PF2DB", uuid.to_ulid_string());
+
+        let ulid = Uuid::from_ulid_str("01HF7YAT00FAQRVVR14D2PF2DB").unwrap();
+
+        assert_eq!(uuid, Uuid::from_ulid_v7(ulid.as_u128(), true).unwrap());
+        assert_eq!(uuid, Uuid::from_ulid_v7(ulid.as_u128(), false).unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    #[cfg(all(feature = "std", feature = "v7"))]
+    fn test_monotonic_ulids() {
+        use crate::{ContextV7, Timestamp};
+
+        let context = ContextV7::new().with_monotonic_random();
+
+        let mut last = Uuid::new_v7(Timestamp::from_unix(&context, 1_700_000_000, 0));
+
+        for _ in 0..10 {
+            let next = Uuid::new_v7(Timestamp::from_unix(&context, 1_700_000_000, 0));
+
+            assert_eq!(last.as_u128() + 1, next.as_u128());
+            assert!(last.to_ulid_string() < next.to_ulid_string());
+
+            last = next;
+        }
+    }
+}
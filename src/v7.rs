@@ -69,10 +69,7 @@ impl Uuid {
     ///
     /// * [UUID Version 7 in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-5.7)
     pub fn new_v7(ts: Timestamp) -> Self {
-        let (secs, nanos) = ts.to_unix();
-        let millis = secs
-            .saturating_mul(1000)
-            .saturating_add(nanos as u64 / 1_000_000);
+        let millis = ts.unix_millis();
 
         let (counter, counter_bits) = ts.counter();
 
@@ -114,10 +111,11 @@ impl Uuid {
                 mask_counter_into_random(rng::u128(), counter, counter_bits)
             }
             // The counter overrides all bits
+            // Its least significant 74 bits are aligned with `rand_a`, `var`, and `rand_b`
             74.. => {
-                let (counter, _) = shift_counter_over_variant(counter, counter_bits as u32);
+                let (counter, _) = shift_counter_over_variant(counter & (u128::MAX >> 54), 74);
 
-                counter
+                counter << 48
             }
         };
 
@@ -281,6 +279,23 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_new_full_counter() {
+        let counter = 0x2ab_cdef_0123_4567_89ab;
+
+        let uuid = Uuid::new_v7(Timestamp::from_unix_time(1_700_000_000, 0, counter, 74));
+
+        assert_eq!("018bcfe5-6800-7aaf-8def-0123456789ab", uuid.to_string());
+
+        let next = Uuid::new_v7(Timestamp::from_unix_time(1_700_000_000, 0, counter + 1, 74));
+
+        assert_eq!(uuid.as_u128() + 1, next.as_u128());
+    }
+
     #[test]
     #[cfg_attr(
         all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
@@ -0,0 +1,631 @@
+//! Checking UUIDs from untrusted sources.
+//!
+//! A UUID supplied by a client can be well-formed but still suspicious. It may use a
+//! variant or version your system never generates, carry a timestamp that's far in
+//! the future, or embed the MAC address of the machine that generated it.
+//! [`Uuid::validate`] checks a UUID against a configurable [`Policy`] and returns
+//! any [`Finding`]s.
+//!
+//! # Examples
+//!
+//! ```
+//! # use uuid::{Uuid, validate::{Finding, Policy}};
+//! # fn main() -> Result<(), uuid::Error> {
+//! let policy = Policy::new();
+//!
+//! let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+//! assert!(uuid.validate(&policy).is_empty());
+//!
+//! let uuid = Uuid::parse_str("67e55044-10b1-926f-9247-bb680e5fe0c8")?;
+//! assert_eq!(&[Finding::ReservedVersion(9)], uuid.validate(&policy).as_slice());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{std::fmt, Timestamp, Uuid, Variant, Version};
+
+#[cfg(feature = "std")]
+use crate::std::time::Duration;
+
+// The start of 2020, which no legitimate time-based UUID should be older than by default
+const DEFAULT_NOT_BEFORE: Timestamp = Timestamp::from_unix_time(1_577_836_800, 0, 0, 0);
+
+/// A set of rules to check UUIDs against.
+///
+/// A policy is built up from [`Policy::new`], which is strict by default:
+///
+/// - Only the RFC 9562 variant is allowed.
+/// - Only versions 1 through 8 are allowed.
+/// - The nil and max UUIDs aren't allowed.
+/// - Timestamps in versions 1, 6, and 7 can't be before 2020.
+/// - Node IDs in versions 1 and 6 must have the multicast bit set, which
+///   indicates they're random rather than a hardware MAC address.
+///
+/// # Examples
+///
+/// Accept Microsoft GUIDs, reject version 1 UUIDs, and reject
+/// timestamps that are more than a minute in the future:
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// # use std::time::Duration;
+/// # use uuid::{Variant, Version, validate::Policy};
+/// let policy = Policy::new()
+///     .allow_variant(Variant::Microsoft)
+///     .deny_version(Version::Mac)
+///     .max_future(Some(Duration::from_secs(60)));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Policy {
+    // A bit is set for each allowed `Variant`
+    variants: u8,
+    // A bit is set for each allowed version number
+    versions: u16,
+    allow_nil: bool,
+    allow_max: bool,
+    allow_hardware_node_id: bool,
+    not_before: Option<Timestamp>,
+    not_after: Option<Timestamp>,
+    #[cfg(feature = "std")]
+    max_future: Option<Duration>,
+}
+
+impl Policy {
+    /// Create a strict policy.
+    ///
+    /// See the type-level docs for the rules applied by default.
+    pub const fn new() -> Self {
+        Policy {
+            variants: 1 << Variant::RFC4122 as u8,
+            versions: 0b1_1111_1110,
+            allow_nil: false,
+            allow_max: false,
+            allow_hardware_node_id: false,
+            not_before: Some(DEFAULT_NOT_BEFORE),
+            not_after: None,
+            #[cfg(feature = "std")]
+            max_future: None,
+        }
+    }
+
+    /// Allow UUIDs with the given variant.
+    ///
+    /// Only the variant is checked for UUIDs that don't use the RFC 9562 variant,
+    /// since their other fields aren't defined.
+    pub const fn allow_variant(mut self, variant: Variant) -> Self {
+        self.variants |= 1 << variant as u8;
+        self
+    }
+
+    /// Don't allow UUIDs with the given variant.
+    pub const fn deny_variant(mut self, variant: Variant) -> Self {
+        self.variants &= !(1 << variant as u8);
+        self
+    }
+
+    /// Allow UUIDs with the given version.
+    ///
+    /// The nil and max UUIDs are allowed with [`Policy::allow_nil`]
+    /// and [`Policy::allow_max`] instead.
+    pub const fn allow_version(mut self, version: Version) -> Self {
+        self.versions |= 1 << version as u8;
+        self
+    }
+
+    /// Don't allow UUIDs with the given version.
+    pub const fn deny_version(mut self, version: Version) -> Self {
+        self.versions &= !(1 << version as u8);
+        self
+    }
+
+    /// Allow the nil UUID.
+    pub const fn allow_nil(mut self) -> Self {
+        self.allow_nil = true;
+        self
+    }
+
+    /// Allow the max UUID.
+    pub const fn allow_max(mut self) -> Self {
+        self.allow_max = true;
+        self
+    }
+
+    /// Allow node IDs in versions 1 and 6 UUIDs that look like hardware MAC addresses.
+    pub const fn allow_hardware_node_id(mut self) -> Self {
+        self.allow_hardware_node_id = true;
+        self
+    }
+
+    /// Set the earliest timestamp allowed in versions 1, 6, and 7 UUIDs.
+    ///
+    /// Passing `None` allows any timestamp in the past.
+    pub const fn not_before(mut self, ts: Option<Timestamp>) -> Self {
+        self.not_before = ts;
+        self
+    }
+
+    /// Set the latest timestamp allowed in versions 1, 6, and 7 UUIDs.
+    ///
+    /// Passing `None` allows any timestamp in the future, unless
+    /// [`Policy::max_future`] is also set.
+    pub const fn not_after(mut self, ts: Option<Timestamp>) -> Self {
+        self.not_after = ts;
+        self
+    }
+
+    /// Set how far past the current system time a timestamp in a
+    /// version 1, 6, or 7 UUID is allowed to be.
+    ///
+    /// The current time is read each time a UUID is validated.
+    #[cfg(feature = "std")]
+    pub const fn max_future(mut self, max: Option<Duration>) -> Self {
+        self.max_future = max;
+        self
+    }
+
+    const fn allows_variant(&self, variant: Variant) -> bool {
+        self.variants & (1 << variant as u8) != 0
+    }
+
+    const fn allows_version(&self, version: Version) -> bool {
+        self.versions & (1 << version as u8) != 0
+    }
+
+    fn latest(&self) -> Option<Timestamp> {
+        #[cfg(feature = "std")]
+        {
+            if let Some(max) = self.max_future {
+                let (seconds, nanos) = Timestamp::now(crate::NoContext).to_unix();
+                let latest = Duration::new(seconds, nanos).saturating_add(max);
+                let latest =
+                    Timestamp::from_unix_time(latest.as_secs(), latest.subsec_nanos(), 0, 0);
+
+                return Some(match self.not_after {
+                    Some(not_after) if not_after.to_unix() < latest.to_unix() => not_after,
+                    _ => latest,
+                });
+            }
+        }
+
+        self.not_after
+    }
+}
+
+impl Default for Policy {
+    #[inline]
+    fn default() -> Self {
+        Policy::new()
+    }
+}
+
+/// A problem found when validating a UUID against a [`Policy`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Finding {
+    /// The UUID is nil.
+    Nil,
+    /// The UUID is max.
+    Max,
+    /// The variant isn't allowed.
+    Variant(Variant),
+    /// The version isn't allowed.
+    Version(Version),
+    /// The version isn't defined by RFC 9562.
+    ReservedVersion(usize),
+    /// The timestamp is before the earliest allowed time.
+    TimestampTooEarly(Timestamp),
+    /// The timestamp is after the latest allowed time.
+    TimestampTooLate(Timestamp),
+    /// The node ID doesn't have its multicast bit set, so it's likely to be the
+    /// hardware MAC address of the machine that generated the UUID.
+    HardwareNodeId([u8; 6]),
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Finding::Nil => write!(f, "the UUID is nil"),
+            Finding::Max => write!(f, "the UUID is max"),
+            Finding::Variant(variant) => write!(f, "the {} variant is not allowed", variant),
+            Finding::Version(version) => {
+                write!(f, "version {} is not allowed", version as u8)
+            }
+            Finding::ReservedVersion(version) => write!(f, "version {} is reserved", version),
+            Finding::TimestampTooEarly(_) => write!(f, "the timestamp is too early"),
+            Finding::TimestampTooLate(_) => write!(f, "the timestamp is too late"),
+            Finding::HardwareNodeId(_) => {
+                write!(f, "the node ID looks like a hardware MAC address")
+            }
+        }
+    }
+}
+
+// A UUID can have at most one finding each for its version, timestamp, and node ID
+const MAX_FINDINGS: usize = 3;
+
+/// The [`Finding`]s from validating a UUID.
+///
+/// This type is returned by [`Uuid::validate`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Findings {
+    findings: [Finding; MAX_FINDINGS],
+    len: usize,
+}
+
+impl Findings {
+    const fn new() -> Self {
+        Findings {
+            findings: [Finding::Nil; MAX_FINDINGS],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, finding: Finding) {
+        self.findings[self.len] = finding;
+        self.len += 1;
+    }
+
+    /// Whether there are no findings, so the UUID passed validation.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of findings.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Get the findings as a slice.
+    pub fn as_slice(&self) -> &[Finding] {
+        &self.findings[..self.len]
+    }
+
+    /// Iterate over the findings.
+    pub fn iter(&self) -> crate::std::slice::Iter<'_, Finding> {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Findings {
+    type Item = &'a Finding;
+    type IntoIter = crate::std::slice::Iter<'a, Finding>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Uuid {
+    /// Check this UUID against a [`Policy`].
+    ///
+    /// The variant is checked using [`Uuid::get_variant`], and the version using
+    /// [`Uuid::get_version`]. For UUIDs using the RFC 9562 variant, the timestamp
+    /// is checked using [`Uuid::get_timestamp`] and the node ID using [`Uuid::get_node_id`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, validate::{Finding, Policy}};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// // A version 1 UUID with a hardware MAC address
+    /// let uuid = Uuid::parse_str("f9168c5e-ceb2-11ea-b6bf-00155d8c7b0f")?;
+    ///
+    /// let findings = uuid.validate(&Policy::new());
+    ///
+    /// assert_eq!(
+    ///     &[Finding::HardwareNodeId([0x00, 0x15, 0x5d, 0x8c, 0x7b, 0x0f])],
+    ///     findings.as_slice(),
+    /// );
+    ///
+    /// let findings = uuid.validate(&Policy::new().allow_hardware_node_id());
+    ///
+    /// assert!(findings.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self, policy: &Policy) -> Findings {
+        let mut findings = Findings::new();
+
+        if self.is_nil() {
+            if !policy.allow_nil {
+                findings.push(Finding::Nil);
+            }
+
+            return findings;
+        }
+
+        if self.is_max() {
+            if !policy.allow_max {
+                findings.push(Finding::Max);
+            }
+
+            return findings;
+        }
+
+        let variant = self.get_variant();
+
+        if !policy.allows_variant(variant) {
+            findings.push(Finding::Variant(variant));
+
+            return findings;
+        }
+
+        // The remaining fields are only defined for the RFC 9562 variant
+        if variant != Variant::RFC4122 {
+            return findings;
+        }
+
+        match self.get_version() {
+            Some(version) if !policy.allows_version(version) => {
+                findings.push(Finding::Version(version))
+            }
+            Some(_) => (),
+            None => findings.push(Finding::ReservedVersion(self.get_version_num())),
+        }
+
+        if let Some(ts) = self.get_timestamp() {
+            // Versions 1 and 6 count from 1582, and converting a timestamp before 1970
+            // to Unix time wraps around, so compare them in Gregorian ticks instead
+            let gregorian = matches!(self.get_version(), Some(Version::Mac | Version::SortMac));
+            let key = |ts: Timestamp| {
+                if gregorian {
+                    (ts.to_gregorian().0, 0)
+                } else {
+                    ts.to_unix()
+                }
+            };
+
+            match (policy.not_before, policy.latest()) {
+                (Some(not_before), _) if key(ts) < key(not_before) => {
+                    findings.push(Finding::TimestampTooEarly(ts))
+                }
+                (_, Some(latest)) if key(ts) > key(latest) => {
+                    findings.push(Finding::TimestampTooLate(ts))
+                }
+                _ => (),
+            }
+        }
+
+        if let Some(node_id) = self.get_node_id() {
+            if !policy.allow_hardware_node_id && node_id[0] & 0x01 == 0 {
+                findings.push(Finding::HardwareNodeId(node_id));
+            }
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    use crate::std::string::ToString;
+
+    fn parse(uuid: &str) -> Uuid {
+        Uuid::parse_str(uuid).unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_valid() {
+        let policy = Policy::new();
+
+        for uuid in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "017f22e2-79b0-7cc3-98c4-dc0c0c07398f",
+            "1ec9414c-232a-6b00-b3c8-9f6bdeced846",
+            "a1a2a3a4-b1b2-81c2-91d2-d3d4d5d6d7d8",
+        ] {
+            assert!(parse(uuid).validate(&policy).is_empty(), "{}", uuid);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_nil_max() {
+        assert_eq!(
+            &[Finding::Nil],
+            Uuid::nil().validate(&Policy::new()).as_slice()
+        );
+        assert_eq!(
+            &[Finding::Max],
+            Uuid::max().validate(&Policy::new()).as_slice()
+        );
+
+        assert!(Uuid::nil().validate(&Policy::new().allow_nil()).is_empty());
+        assert!(Uuid::max().validate(&Policy::new().allow_max()).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_variant() {
+        let uuid = parse("67e55044-10b1-426f-c247-bb680e5fe0c8");
+
+        assert_eq!(
+            &[Finding::Variant(Variant::Microsoft)],
+            uuid.validate(&Policy::new()).as_slice()
+        );
+        assert!(uuid
+            .validate(&Policy::new().allow_variant(Variant::Microsoft))
+            .is_empty());
+
+        let uuid = parse("67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+        assert_eq!(
+            &[Finding::Variant(Variant::RFC4122)],
+            uuid.validate(&Policy::new().deny_variant(Variant::RFC4122))
+                .as_slice()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_version() {
+        let uuid = parse("67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+        assert_eq!(
+            &[Finding::Version(Version::Random)],
+            uuid.validate(&Policy::new().deny_version(Version::Random))
+                .as_slice()
+        );
+
+        for (uuid, version) in [
+            ("67e55044-10b1-026f-9247-bb680e5fe0c8", 0),
+            ("67e55044-10b1-926f-9247-bb680e5fe0c8", 9),
+            ("67e55044-10b1-f26f-9247-bb680e5fe0c8", 15),
+        ] {
+            assert_eq!(
+                &[Finding::ReservedVersion(version)],
+                parse(uuid).validate(&Policy::new()).as_slice()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_timestamp() {
+        // 2022-02-22T19:22:22Z
+        let uuid = parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f");
+        let ts = uuid.get_timestamp().unwrap();
+
+        let after = Timestamp::from_unix_time(1_700_000_000, 0, 0, 0);
+        let before = Timestamp::from_unix_time(1_600_000_000, 0, 0, 0);
+
+        assert_eq!(
+            &[Finding::TimestampTooEarly(ts)],
+            uuid.validate(&Policy::new().not_before(Some(after)))
+                .as_slice()
+        );
+        assert_eq!(
+            &[Finding::TimestampTooLate(ts)],
+            uuid.validate(&Policy::new().not_after(Some(before)))
+                .as_slice()
+        );
+
+        // A timestamp from 2010
+        let uuid =
+            crate::Builder::from_unix_timestamp_millis(1_262_304_000_000, &[0x11; 10]).into_uuid();
+
+        assert_eq!(
+            &[Finding::TimestampTooEarly(uuid.get_timestamp().unwrap())],
+            uuid.validate(&Policy::new()).as_slice()
+        );
+        assert!(uuid.validate(&Policy::new().not_before(None)).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_timestamp_before_unix_epoch() {
+        // A version 1 UUID from 1583, which wraps around if converted to Unix time
+        let uuid =
+            crate::Builder::from_gregorian_timestamp(1_000_000, 0, &[1, 2, 3, 4, 5, 6]).into_uuid();
+        let ts = uuid.get_timestamp().unwrap();
+
+        assert_eq!(
+            &[Finding::TimestampTooEarly(ts)],
+            uuid.validate(&Policy::new()).as_slice()
+        );
+        assert_eq!(
+            &[Finding::TimestampTooLate(ts)],
+            uuid.validate(
+                &Policy::new()
+                    .not_before(None)
+                    .not_after(Some(Timestamp::from_gregorian_time(999_999, 0)))
+            )
+            .as_slice()
+        );
+        assert!(uuid.validate(&Policy::new().not_before(None)).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_max_future() {
+        // The max timestamp in a version 7 UUID is in the year 10889
+        let uuid = parse("ffffffff-ffff-7cc3-98c4-dc0c0c07398f");
+        let policy = Policy::new().max_future(Some(Duration::from_secs(60)));
+
+        assert_eq!(
+            &[Finding::TimestampTooLate(uuid.get_timestamp().unwrap())],
+            uuid.validate(&policy).as_slice()
+        );
+
+        let uuid = parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f");
+
+        assert!(uuid.validate(&policy).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_node_id() {
+        // A version 6 UUID with a random node ID
+        let uuid = parse("1ec9414c-232a-6b00-b3c8-9f6bdeced846");
+
+        assert!(uuid.validate(&Policy::new()).is_empty());
+
+        // A version 6 UUID with a hardware MAC address
+        let uuid = parse("1ec9414c-232a-6b00-b3c8-9e6bdeced846");
+
+        assert_eq!(
+            &[Finding::HardwareNodeId([
+                0x9e, 0x6b, 0xde, 0xce, 0xd8, 0x46
+            ])],
+            uuid.validate(&Policy::new()).as_slice()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_multiple_findings() {
+        // A version 1 UUID from 1998 with a hardware MAC address
+        let uuid = parse("f9168c5e-ceb2-11d1-b6bf-00155d8c7b0f");
+
+        let findings = uuid.validate(&Policy::new().deny_version(Version::Mac));
+
+        assert_eq!(3, findings.len());
+        assert_eq!(
+            [
+                "version 1 is not allowed",
+                "the timestamp is too early",
+                "the node ID looks like a hardware MAC address",
+            ],
+            [
+                findings.as_slice()[0].to_string(),
+                findings.as_slice()[1].to_string(),
+                findings.as_slice()[2].to_string(),
+            ]
+        );
+    }
+}
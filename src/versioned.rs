@@ -0,0 +1,432 @@
+//! Wrapper types for UUIDs that are guaranteed to be a specific version.
+//!
+//! Each type, like [`UuidV7`], can only be constructed from a [`Uuid`] with the
+//! RFC 9562 variant and the matching version. That makes it possible to require
+//! time-ordered UUIDs in places like database keys using the type system. Since
+//! no version of UUID is nil, each type has a niche, so `Option<UuidV7>` takes
+//! up the same space as `Uuid`.
+
+use core::{cmp::Ordering, fmt, str::FromStr};
+
+use crate::{
+    error::{Error, ErrorKind},
+    non_nil::NonNilUuid,
+    timestamp, Timestamp, Uuid, Variant, Version,
+};
+
+macro_rules! versioned {
+    ($(#[$meta:meta])* $name:ident => $version:ident($num:literal)) => {
+        $(#[$meta])*
+        ///
+        /// # ABI
+        ///
+        #[doc = concat!("The `", stringify!($name), "` type does not yet have a stable ABI.")]
+        #[doc = concat!("It is currently only guaranteed that `", stringify!($name), "` and")]
+        #[doc = concat!("`Option<", stringify!($name), ">` are the same size as `Uuid`.")]
+        #[repr(transparent)]
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(NonNilUuid);
+
+        impl $name {
+            #[doc = concat!("Creates a `", stringify!($name), "` if the UUID is version ", stringify!($num), ".")]
+            ///
+            /// The version is checked using [`Uuid::get_version`], and the variant
+            /// using [`Uuid::get_variant`]. Only the RFC 9562 variant defines versions,
+            /// so UUIDs with any other variant aren't accepted.
+            pub const fn from_uuid(uuid: Uuid) -> Option<Self> {
+                match (uuid.get_variant(), uuid.get_version()) {
+                    // SAFETY: A UUID with a version other than `Nil` is never nil
+                    (Variant::RFC4122, Some(Version::$version)) => {
+                        Some($name(unsafe { NonNilUuid::new_unchecked(uuid) }))
+                    }
+                    _ => None,
+                }
+            }
+
+            /// Get the underlying [`Uuid`] value.
+            #[inline]
+            pub const fn get(self) -> Uuid {
+                self.0.get()
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.get(), f)
+            }
+        }
+
+        impl PartialEq<Uuid> for $name {
+            fn eq(&self, other: &Uuid) -> bool {
+                self.get() == *other
+            }
+        }
+
+        impl PartialEq<$name> for Uuid {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.get()
+            }
+        }
+
+        impl PartialOrd<Uuid> for $name {
+            fn partial_cmp(&self, other: &Uuid) -> Option<Ordering> {
+                self.get().partial_cmp(other)
+            }
+        }
+
+        impl PartialOrd<$name> for Uuid {
+            fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                self.partial_cmp(&other.get())
+            }
+        }
+
+        impl From<$name> for Uuid {
+            fn from(uuid: $name) -> Self {
+                uuid.get()
+            }
+        }
+
+        impl From<$name> for NonNilUuid {
+            fn from(uuid: $name) -> Self {
+                uuid.0
+            }
+        }
+
+        impl TryFrom<Uuid> for $name {
+            type Error = Error;
+
+            fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+                match $name::from_uuid(uuid) {
+                    Some(versioned) => Ok(versioned),
+                    None if uuid.get_version_num() != $num => Err(Error(ErrorKind::UnexpectedVersion {
+                        expected: $num,
+                        found: uuid.get_version_num(),
+                    })),
+                    None => Err(Error(ErrorKind::UnexpectedVariant {
+                        found: uuid.get_variant(),
+                    })),
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(uuid_str: &str) -> Result<Self, Self::Err> {
+                $name::try_from(Uuid::parse_str(uuid_str)?)
+            }
+        }
+    };
+}
+
+versioned! {
+    /// A UUID that is guaranteed to be [version 1](https://www.ietf.org/rfc/rfc9562.html#name-uuid-version-1).
+    UuidV1 => Mac(1)
+}
+
+versioned! {
+    /// A UUID that is guaranteed to be [version 3](https://www.ietf.org/rfc/rfc9562.html#name-uuid-version-3).
+    UuidV3 => Md5(3)
+}
+
+versioned! {
+    /// A UUID that is guaranteed to be [version 4](https://www.ietf.org/rfc/rfc9562.html#name-uuid-version-4).
+    UuidV4 => Random(4)
+}
+
+versioned! {
+    /// A UUID that is guaranteed to be [version 5](https://www.ietf.org/rfc/rfc9562.html#name-uuid-version-5).
+    UuidV5 => Sha1(5)
+}
+
+versioned! {
+    /// A UUID that is guaranteed to be [version 6](https://www.ietf.org/rfc/rfc9562.html#name-uuid-version-6).
+    UuidV6 => SortMac(6)
+}
+
+versioned! {
+    /// A UUID that is guaranteed to be [version 7](https://www.ietf.org/rfc/rfc9562.html#name-uuid-version-7).
+    ///
+    /// # Examples
+    ///
+    /// Requiring time-ordered UUIDs:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, UuidV7};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid: UuidV7 = "017f22e2-79b0-7cc3-98c4-dc0c0c07398f".parse()?;
+    ///
+    /// assert_eq!((1645557742, 0), uuid.timestamp().to_unix());
+    ///
+    /// // A version 4 UUID is rejected
+    /// assert!("67e55044-10b1-426f-9247-bb680e5fe0c8".parse::<UuidV7>().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    UuidV7 => SortRand(7)
+}
+
+versioned! {
+    /// A UUID that is guaranteed to be [version 8](https://www.ietf.org/rfc/rfc9562.html#name-uuid-version-8).
+    UuidV8 => Custom(8)
+}
+
+impl UuidV1 {
+    /// Create a new version 1 UUID using the current system time and node ID.
+    ///
+    /// See [`Uuid::now_v1`] for more details.
+    #[cfg(all(feature = "v1", feature = "std", feature = "rng"))]
+    pub fn now(node_id: &[u8; 6]) -> Self {
+        UuidV1::from_uuid(Uuid::now_v1(node_id)).expect("a version 1 UUID")
+    }
+
+    /// Create a new version 1 UUID using the given timestamp and node ID.
+    ///
+    /// See [`Uuid::new_v1`] for more details.
+    #[cfg(feature = "v1")]
+    pub fn new(ts: Timestamp, node_id: &[u8; 6]) -> Self {
+        UuidV1::from_uuid(Uuid::new_v1(ts, node_id)).expect("a version 1 UUID")
+    }
+
+    /// Get the timestamp embedded in the UUID.
+    pub const fn timestamp(self) -> Timestamp {
+        let (ticks, counter) = timestamp::decode_gregorian_timestamp(&self.get());
+
+        Timestamp::from_gregorian_time(ticks, counter)
+    }
+}
+
+impl UuidV3 {
+    /// Create a new version 3 UUID using an MD5 hash of a namespace and name.
+    ///
+    /// See [`Uuid::new_v3`] for more details.
+    #[cfg(feature = "v3")]
+    pub fn new(namespace: &Uuid, name: &[u8]) -> Self {
+        UuidV3::from_uuid(Uuid::new_v3(namespace, name)).expect("a version 3 UUID")
+    }
+}
+
+impl UuidV4 {
+    /// Create a new random version 4 UUID.
+    ///
+    /// See [`Uuid::new_v4`] for more details.
+    #[cfg(feature = "v4")]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        UuidV4::from_uuid(Uuid::new_v4()).expect("a version 4 UUID")
+    }
+}
+
+impl UuidV5 {
+    /// Create a new version 5 UUID using a SHA-1 hash of a namespace and name.
+    ///
+    /// See [`Uuid::new_v5`] for more details.
+    #[cfg(feature = "v5")]
+    pub fn new(namespace: &Uuid, name: &[u8]) -> Self {
+        UuidV5::from_uuid(Uuid::new_v5(namespace, name)).expect("a version 5 UUID")
+    }
+}
+
+impl UuidV6 {
+    /// Create a new version 6 UUID using the current system time and node ID.
+    ///
+    /// See [`Uuid::now_v6`] for more details.
+    #[cfg(all(feature = "v6", feature = "std", feature = "rng"))]
+    pub fn now(node_id: &[u8; 6]) -> Self {
+        UuidV6::from_uuid(Uuid::now_v6(node_id)).expect("a version 6 UUID")
+    }
+
+    /// Create a new version 6 UUID using the given timestamp and node ID.
+    ///
+    /// See [`Uuid::new_v6`] for more details.
+    #[cfg(feature = "v6")]
+    pub fn new(ts: Timestamp, node_id: &[u8; 6]) -> Self {
+        UuidV6::from_uuid(Uuid::new_v6(ts, node_id)).expect("a version 6 UUID")
+    }
+
+    /// Get the timestamp embedded in the UUID.
+    pub const fn timestamp(self) -> Timestamp {
+        let (ticks, counter) = timestamp::decode_sorted_gregorian_timestamp(&self.get());
+
+        Timestamp::from_gregorian_time(ticks, counter)
+    }
+}
+
+impl UuidV7 {
+    /// Create a new version 7 UUID using the current system time.
+    ///
+    /// See [`Uuid::now_v7`] for more details.
+    #[cfg(all(feature = "v7", feature = "std"))]
+    pub fn now() -> Self {
+        UuidV7::from_uuid(Uuid::now_v7()).expect("a version 7 UUID")
+    }
+
+    /// Create a new version 7 UUID using a time value and random bytes.
+    ///
+    /// See [`Uuid::new_v7`] for more details.
+    #[cfg(feature = "v7")]
+    pub fn new(ts: Timestamp) -> Self {
+        UuidV7::from_uuid(Uuid::new_v7(ts)).expect("a version 7 UUID")
+    }
+
+    /// Get the timestamp embedded in the UUID.
+    ///
+    /// The timestamp has millisecond precision.
+    pub const fn timestamp(self) -> Timestamp {
+        let millis = timestamp::decode_unix_timestamp_millis(&self.get());
+
+        let seconds = millis / 1000;
+        let nanos = ((millis % 1000) * 1_000_000) as u32;
+
+        Timestamp::from_unix_time(seconds, nanos, 0, 0)
+    }
+}
+
+impl UuidV8 {
+    /// Create a new version 8 UUID using the supplied bytes.
+    ///
+    /// See [`Uuid::new_v8`] for more details.
+    #[cfg(feature = "v8")]
+    pub const fn new(buf: [u8; 16]) -> Self {
+        // SAFETY: A version 8 UUID is never nil
+        UuidV8(unsafe { NonNilUuid::new_unchecked(Uuid::new_v8(buf)) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        std::string::ToString,
+        tests::{some_uuid_v1, some_uuid_v4, some_uuid_v6, some_uuid_v7},
+    };
+
+    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_versioned_with_option_size() {
+        assert_eq!(
+            core::mem::size_of::<Option<UuidV4>>(),
+            core::mem::size_of::<Uuid>()
+        );
+        assert_eq!(
+            core::mem::size_of::<Option<UuidV7>>(),
+            core::mem::size_of::<Uuid>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_versioned_try_from() {
+        let v4 = some_uuid_v4();
+        let v7 = some_uuid_v7();
+
+        assert_eq!(v4, UuidV4::try_from(v4).unwrap());
+        assert_eq!(v7, UuidV7::try_from(v7).unwrap());
+        assert_eq!(v7, Uuid::from(UuidV7::try_from(v7).unwrap()));
+
+        assert!(UuidV4::try_from(v7).is_err());
+        assert!(UuidV7::try_from(v4).is_err());
+        assert!(UuidV7::try_from(Uuid::nil()).is_err());
+        assert!(UuidV7::try_from(Uuid::max()).is_err());
+
+        assert_eq!(
+            "unexpected UUID version: expected 7, found 4",
+            UuidV7::try_from(v4).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_versioned_variant() {
+        // Version 7 nibbles with the NCS, Microsoft, and future variants
+        for (uuid, variant) in [
+            ("017f22e2-79b0-7cc3-58c4-dc0c0c07398f", Variant::NCS),
+            ("017f22e2-79b0-7cc3-c8c4-dc0c0c07398f", Variant::Microsoft),
+            ("017f22e2-79b0-7cc3-e8c4-dc0c0c07398f", Variant::Future),
+        ] {
+            let uuid = Uuid::parse_str(uuid).unwrap();
+
+            assert_eq!(Some(Version::SortRand), uuid.get_version());
+            assert_eq!(variant, uuid.get_variant());
+
+            assert_eq!(None, UuidV7::from_uuid(uuid));
+            assert_eq!(
+                Error(ErrorKind::UnexpectedVariant { found: variant }),
+                UuidV7::try_from(uuid).unwrap_err()
+            );
+        }
+
+        assert_eq!(
+            "unexpected UUID variant: expected RFC 9562, found Microsoft",
+            "017f22e2-79b0-7cc3-c8c4-dc0c0c07398f"
+                .parse::<UuidV7>()
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_versioned_from_str() {
+        let uuid = "017f22e2-79b0-7cc3-98c4-dc0c0c07398f";
+
+        assert_eq!(uuid, uuid.parse::<UuidV7>().unwrap().to_string());
+
+        assert_eq!(
+            "unexpected UUID version: expected 4, found 7",
+            uuid.parse::<UuidV4>().unwrap_err().to_string()
+        );
+        assert_eq!(
+            Uuid::parse_str("not a uuid").unwrap_err(),
+            "not a uuid".parse::<UuidV7>().unwrap_err()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    fn test_versioned_timestamp() {
+        let v1 = UuidV1::try_from(some_uuid_v1()).unwrap();
+        let v6 = UuidV6::try_from(some_uuid_v6()).unwrap();
+        let v7 = UuidV7::try_from(some_uuid_v7()).unwrap();
+
+        assert_eq!(Some(v1.timestamp()), some_uuid_v1().get_timestamp());
+        assert_eq!(Some(v6.timestamp()), some_uuid_v6().get_timestamp());
+        assert_eq!(Some(v7.timestamp()), some_uuid_v7().get_timestamp());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")),
+        wasm_bindgen_test
+    )]
+    #[cfg(all(feature = "v4", feature = "v7", feature = "std"))]
+    fn test_versioned_new() {
+        assert_eq!(Some(Version::Random), UuidV4::new().get().get_version());
+        assert_eq!(Some(Version::SortRand), UuidV7::now().get().get_version());
+    }
+}
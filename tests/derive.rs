@@ -0,0 +1,121 @@
+#![cfg(feature = "derive")]
+
+use uuid::{Uuid, UuidNewtype};
+
+#[derive(UuidNewtype, Debug, Clone, Copy, PartialEq)]
+struct AnyId(Uuid);
+
+#[derive(UuidNewtype, Debug, Clone, Copy, PartialEq)]
+#[uuid(version = 7, format = "simple")]
+struct OrderId(Uuid);
+
+#[derive(UuidNewtype, Debug, Clone, Copy, PartialEq)]
+#[uuid(version = 4, format = "urn")]
+struct UserId(Uuid);
+
+const V4: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+const V7: &str = "017f22e2-79b0-7cc3-98c4-dc0c0c07398f";
+
+#[test]
+fn test_unrestricted() {
+    let uuid = Uuid::parse_str(V4).unwrap();
+    let id = AnyId::from(uuid);
+
+    assert_eq!(V4, id.to_string());
+    assert_eq!(id, V4.parse().unwrap());
+    assert_eq!(id, "67e5504410b1426f9247bb680e5fe0c8".parse().unwrap());
+
+    assert_eq!(uuid, Uuid::from(id));
+    assert_eq!(&uuid, id.as_ref());
+    assert_eq!(&uuid, id.as_uuid());
+    assert_eq!(uuid, id.into_uuid());
+}
+
+#[test]
+fn test_restricted_version() {
+    let id: OrderId = V7.parse::<Uuid>().unwrap().try_into().unwrap();
+
+    assert_eq!("017f22e279b07cc398c4dc0c0c07398f", id.to_string());
+    assert_eq!(id, "017f22e279b07cc398c4dc0c0c07398f".parse().unwrap());
+
+    // The configured format is required when parsing
+    assert!(V7.parse::<OrderId>().is_err());
+
+    // Other versions are rejected
+    assert_eq!(
+        "unexpected UUID version: expected 7, found 4",
+        OrderId::try_from(Uuid::parse_str(V4).unwrap())
+            .unwrap_err()
+            .to_string()
+    );
+    assert!("67e5504410b1426f9247bb680e5fe0c8"
+        .parse::<OrderId>()
+        .is_err());
+
+    let user = UserId::try_from(Uuid::parse_str(V4).unwrap()).unwrap();
+
+    assert_eq!(format!("urn:uuid:{}", V4), user.to_string());
+    assert_eq!(user, format!("urn:uuid:{}", V4).parse().unwrap());
+
+    let versioned = uuid::UuidV7::try_from(id).unwrap();
+
+    assert_eq!(id, OrderId::from(versioned));
+
+    // The field is public to the defining module, so the version isn't guaranteed
+    assert!(uuid::UuidV7::try_from(OrderId(Uuid::nil())).is_err());
+}
+
+#[test]
+#[cfg(all(feature = "v4", feature = "v7", feature = "std"))]
+fn test_constructors() {
+    let order = OrderId::now_v7();
+    let user = UserId::new_v4();
+
+    assert_eq!(Some(uuid::Version::SortRand), order.as_uuid().get_version());
+    assert_eq!(Some(uuid::Version::Random), user.as_uuid().get_version());
+}
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+    use super::*;
+
+    #[derive(UuidNewtype, Debug, Clone, Copy, PartialEq)]
+    #[uuid(version = 7, serde)]
+    struct EventId(Uuid);
+
+    #[derive(UuidNewtype, Debug, Clone, Copy, PartialEq)]
+    #[uuid(format = "simple", serde = "compact")]
+    struct BlobId(Uuid);
+
+    #[test]
+    fn test_serde() {
+        let event: EventId = V7.parse().unwrap();
+
+        assert_eq!(
+            format!("\"{}\"", V7),
+            serde_json::to_string(&event).unwrap()
+        );
+        assert_eq!(
+            event,
+            serde_json::from_str::<EventId>(&format!("\"{}\"", V7)).unwrap()
+        );
+
+        let err = serde_json::from_str::<EventId>(&format!("\"{}\"", V4)).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unexpected UUID version: expected 7, found 4"));
+    }
+
+    #[test]
+    fn test_serde_module() {
+        let blob = BlobId::from(Uuid::parse_str(V4).unwrap());
+
+        let json = serde_json::to_string(&blob).unwrap();
+
+        assert_eq!(
+            "[103,229,80,68,16,177,66,111,146,71,187,104,14,95,224,200]",
+            json
+        );
+        assert_eq!(blob, serde_json::from_str::<BlobId>(&json).unwrap());
+    }
+}
@@ -0,0 +1,11 @@
+use uuid::{non_nil_uuid, NonNilUuid};
+
+const _: NonNilUuid = non_nil_uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+const _: NonNilUuid = non_nil_uuid!("67e5504410b1426f9247bb680e5fe0c8");
+const _: NonNilUuid = non_nil_uuid!("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8");
+const _: NonNilUuid = non_nil_uuid!("{67e55044-10b1-426f-9247-bb680e5fe0c8}");
+
+// Max
+const _: NonNilUuid = non_nil_uuid!("ffffffff-ffff-ffff-ffff-ffffffffffff");
+
+fn main() {}